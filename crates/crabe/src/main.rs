@@ -144,7 +144,9 @@ impl System {
                 .step(&self.world, &mut tool_data, &mut command_map);
             self.guard_component
                 .step(&self.world, &mut command_map, &mut ToolCommands);
-            feedback = self.output_component.step(command_map, ToolCommands);
+            feedback = self
+                .output_component
+                .step(&self.world, command_map, ToolCommands);
             thread::sleep(_refresh_rate);
         }
     }
@@ -160,7 +162,9 @@ impl System {
 }
 
 fn main() {
-    let cli = Cli::parse();
+    let mut cli = Cli::parse();
+    // The tracker filter has nothing to work with unless the tracked frames are received.
+    cli.input_config.tracker |= cli.filter_config.use_tracker;
     let env = Env::default()
         .filter_or("CRABE_LOG_LEVEL", "info")
         .write_style_or("CRABE_LOG_STYLE", "always");
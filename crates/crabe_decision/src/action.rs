@@ -1,7 +1,20 @@
+/// The `aim_and_kick` module contains the `AimAndKick` action which turns around the ball to face a target, then kicks it.
+pub mod aim_and_kick;
+/// The `dribble` module contains the `Dribble` action which captures the ball with the dribbler.
+pub mod dribble;
+/// The `dribble_to` module contains the `DribbleTo` action which carries the ball held by the dribbler to a target.
+pub mod dribble_to;
+/// The `go_to_ball` module contains the `GoToBall` action which places the robot behind the ball to push it towards a target.
+pub mod go_to_ball;
 /// The `move_to` module contains the `MoveTo` action which moves a robot to a specific location on the field and a target orientation.
 pub mod move_to;
 /// The `order_raw` module contains the `RawOrder` action which sends a raw command to the robot.
 pub mod order_raw;
+/// The `pass` module contains the `Pass` action which kicks the ball to the receiver of a pass contract.
+pub mod pass;
+
+/// The `receive` module contains the `Receive` action which catches the ball passed following a pass contract.
+pub mod receive;
 
 /// The sequencer module contains the `Sequencer` struct which sequences a collection of actions to be executed.
 pub mod sequencer;
@@ -9,8 +22,14 @@ pub mod sequencer;
 /// The state module contains the State enum which represents the current state of an action.
 pub mod state;
 
+use crate::action::aim_and_kick::AimAndKick;
+use crate::action::dribble::Dribble;
+use crate::action::dribble_to::DribbleTo;
+use crate::action::go_to_ball::GoToBall;
 use crate::action::move_to::MoveTo;
 use crate::action::order_raw::RawOrder;
+use crate::action::pass::Pass;
+use crate::action::receive::Receive;
 use crate::action::sequencer::Sequencer;
 use crabe_framework::data::output::{Command, CommandMap};
 use crabe_framework::data::tool::ToolData;
@@ -36,6 +55,12 @@ pub trait Action {
 #[enum_dispatch]
 pub enum Actions {
     MoveTo(MoveTo),
+    GoToBall(GoToBall),
+    Dribble(Dribble),
+    DribbleTo(DribbleTo),
+    AimAndKick(AimAndKick),
+    Pass(Pass),
+    Receive(Receive),
     RawOrder(RawOrder),
 }
 
@@ -63,6 +88,24 @@ impl ActionWrapper {
         }
     }
 
+    /// Makes the robot move with the given `MoveTo` as its only action. When the robot is
+    /// already moving with a single `MoveTo`, it follows the new one from its current velocity
+    /// instead of restarting its motion profile, so strategies can call it on every step.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: The id of the robot to move.
+    /// * `move_to`: The move to execute.
+    pub fn move_to(&mut self, id: u8, move_to: MoveTo) {
+        let sequencer = self.actions.entry(id).or_default();
+        if let [Actions::MoveTo(current)] = sequencer.actions.as_mut_slice() {
+            current.follow(move_to);
+        } else {
+            sequencer.clear();
+            sequencer.push(move_to.into());
+        }
+    }
+
     /// Clears the sequence of actions to be executed for a given robot.
     ///
     /// # Arguments
@@ -74,6 +117,26 @@ impl ActionWrapper {
         }
     }
 
+    /// Returns whether the given robot has no action left to execute, because its actions
+    /// are all done or one of them failed.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: The id of the robot.
+    pub fn is_idle(&self, id: u8) -> bool {
+        self.actions.get(&id).is_none_or(Sequencer::is_idle)
+    }
+
+    /// Returns whether one of the actions of the given robot failed, the robot being idle
+    /// until its actions are cleared.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: The id of the robot.
+    pub fn has_failed(&self, id: u8) -> bool {
+        self.actions.get(&id).is_some_and(Sequencer::has_failed)
+    }
+
     /// Clears the sequence of actions to be executed of all robot.
     pub fn clear_all(&mut self) {
         self.actions.iter_mut().for_each(|(_, sequencer)| {
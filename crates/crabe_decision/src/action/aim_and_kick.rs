@@ -0,0 +1,148 @@
+use crate::action::dribble::DRIBBLER_SPEED;
+use crate::action::move_to::angle_wrap;
+use crate::action::state::State;
+use crate::action::Action;
+use crabe_framework::data::output::{Command, Kick, MotionCommand};
+use crabe_framework::data::tool::ToolData;
+use crabe_framework::data::world::World;
+use nalgebra::{Point2, Vector3};
+
+/// The gain converting the orientation error in radians into an angular velocity.
+const ORIENTATION_GAIN: f64 = 2.0;
+/// The maximum angular velocity in radians per second while turning with the ball.
+const MAX_AIM_SPEED: f64 = 2.0;
+/// The angle in radians to the target below which the robot kicks.
+const AIM_TOLERANCE: f64 = 0.05;
+/// The deceleration in meters per second squared of the ball rolling on the field.
+pub const BALL_DECELERATION: f64 = 0.5;
+/// The maximum speed in meters per second the ball can be kicked at, allowed by the rules.
+pub const MAX_KICK_SPEED: f64 = 6.0;
+/// The speed in meters per second a shot must still have when reaching the target.
+const SHOT_ARRIVAL_SPEED: f64 = 2.0;
+
+/// Returns the speed in meters per second to kick the ball at so that it still rolls at
+/// `arrival_speed` after `distance` meters.
+pub fn kick_speed(distance: f64, arrival_speed: f64) -> f64 {
+    (arrival_speed.powi(2) + 2.0 * BALL_DECELERATION * distance)
+        .sqrt()
+        .min(MAX_KICK_SPEED)
+}
+
+/// The `AimAndKick` struct represents an action that turns the robot holding the ball around
+/// it, with the dribbler spinning, until it faces the target, then kicks the ball towards it
+/// with a straight kick whose power depends on the distance, unless a speed is given.
+#[derive(Clone)]
+pub struct AimAndKick {
+    /// The current state of the action.
+    state: State,
+    /// The point to kick the ball to.
+    target: Point2<f64>,
+    /// The speed in meters per second to kick the ball at, chosen for the distance if unset.
+    speed: Option<f64>,
+    /// Whether the kick was ordered.
+    kicked: bool,
+}
+
+impl From<&mut AimAndKick> for AimAndKick {
+    fn from(other: &mut AimAndKick) -> AimAndKick {
+        AimAndKick {
+            state: other.state,
+            target: other.target,
+            speed: other.speed,
+            kicked: other.kicked,
+        }
+    }
+}
+
+impl AimAndKick {
+    /// Creates a new `AimAndKick` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `target`: The point to kick the ball to.
+    pub fn new(target: Point2<f64>) -> Self {
+        Self {
+            state: State::Running,
+            target,
+            speed: None,
+            kicked: false,
+        }
+    }
+
+    /// Sets the speed in meters per second to kick the ball at, instead of the speed of a
+    /// shot reaching the target.
+    pub fn with_speed(mut self, speed: f64) -> Self {
+        self.speed = Some(speed.min(MAX_KICK_SPEED));
+        self
+    }
+}
+
+impl Action for AimAndKick {
+    /// Returns the name of the action.
+    fn name(&self) -> String {
+        String::from("AimAndKick")
+    }
+
+    /// Returns the state of the action.
+    fn state(&mut self) -> State {
+        self.state
+    }
+
+    /// Computes the orders to turn around the ball towards the target and to kick it.
+    /// The action is done once the ball left the robot after the kick, and fails if the ball
+    /// is lost before.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: The id of the robot for which the orders are computed.
+    /// * `world`: The current state of the world.
+    /// * `tools`: A collection of external tools used by the action, such as a viewer.
+    fn compute_order(&mut self, id: u8, world: &World, _tools: &mut ToolData) -> Command {
+        let (Some(robot), Some(ball)) = (world.allies_bot.get(&id), &world.ball) else {
+            self.state = State::Failed;
+            return Command::default();
+        };
+        if !robot.has_ball {
+            self.state = if self.kicked {
+                State::Done
+            } else {
+                State::Failed
+            };
+            return Command::default();
+        }
+
+        let to_target = self.target - robot.pose.position;
+        let error = angle_wrap(to_target.y.atan2(to_target.x) - robot.pose.orientation);
+        if error.abs() < AIM_TOLERANCE {
+            self.kicked = true;
+            let speed = self.speed.unwrap_or_else(|| {
+                kick_speed(
+                    (self.target - ball.position_2d()).norm(),
+                    SHOT_ARRIVAL_SPEED,
+                )
+            });
+            return Command {
+                charge: true,
+                kick: Some(Kick::StraightKick {
+                    power: speed as f32,
+                }),
+                ..Default::default()
+            };
+        }
+
+        // Turn around the ball rather than around the center of the robot, so that the ball
+        // stays on the dribbler.
+        let angular = (ORIENTATION_GAIN * error).clamp(-MAX_AIM_SPEED, MAX_AIM_SPEED);
+        let radius = robot.pose.position - ball.position_2d();
+        Command {
+            motion: MotionCommand::global_velocity(Vector3::new(
+                -angular * radius.y,
+                angular * radius.x,
+                angular,
+            )),
+            charge: true,
+            kick: None,
+            dribbler: DRIBBLER_SPEED,
+        }
+    }
+}
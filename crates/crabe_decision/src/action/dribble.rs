@@ -0,0 +1,100 @@
+use crate::action::move_to::angle_wrap;
+use crate::action::state::State;
+use crate::action::Action;
+use crabe_framework::data::output::{Command, MotionCommand};
+use crabe_framework::data::tool::ToolData;
+use crabe_framework::data::world::World;
+use nalgebra::Vector3;
+
+/// The speed in meters per second at which the robot comes in contact with the ball.
+const CAPTURE_SPEED: f64 = 0.3;
+/// The gain converting the orientation error in radians into an angular velocity.
+const ORIENTATION_GAIN: f64 = 3.0;
+/// The distance in meters to the ball beyond which the capture has failed.
+const CAPTURE_RANGE: f64 = 0.5;
+/// The default speed of the dribbler.
+pub const DRIBBLER_SPEED: f32 = 1.0;
+
+/// The `Dribble` struct represents an action that moves the robot slowly towards the ball with
+/// the dribbler spinning, until the ball is held by the robot.
+#[derive(Clone)]
+pub struct Dribble {
+    /// The current state of the action.
+    state: State,
+    /// The speed of the dribbler.
+    dribbler: f32,
+}
+
+impl From<&mut Dribble> for Dribble {
+    fn from(other: &mut Dribble) -> Dribble {
+        Dribble {
+            state: other.state,
+            dribbler: other.dribbler,
+        }
+    }
+}
+
+impl Default for Dribble {
+    fn default() -> Self {
+        Self::new(DRIBBLER_SPEED)
+    }
+}
+
+impl Dribble {
+    /// Creates a new `Dribble` instance spinning the dribbler at the given speed.
+    pub fn new(dribbler: f32) -> Self {
+        Self {
+            state: State::Running,
+            dribbler,
+        }
+    }
+}
+
+impl Action for Dribble {
+    /// Returns the name of the action.
+    fn name(&self) -> String {
+        String::from("Dribble")
+    }
+
+    /// Returns the state of the action.
+    fn state(&mut self) -> State {
+        self.state
+    }
+
+    /// Computes the orders to push the robot against the ball while facing it.
+    /// The action is done once the robot holds the ball, and fails if the ball is lost or
+    /// too far away.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: The id of the robot for which the orders are computed.
+    /// * `world`: The current state of the world.
+    /// * `tools`: A collection of external tools used by the action, such as a viewer.
+    fn compute_order(&mut self, id: u8, world: &World, _tools: &mut ToolData) -> Command {
+        let (Some(robot), Some(ball)) = (world.allies_bot.get(&id), &world.ball) else {
+            self.state = State::Failed;
+            return Command::default();
+        };
+        let mut command = Command {
+            dribbler: self.dribbler,
+            ..Default::default()
+        };
+        if robot.has_ball {
+            self.state = State::Done;
+            return command;
+        }
+
+        let to_ball = ball.position_2d() - robot.pose.position;
+        if to_ball.norm() > CAPTURE_RANGE {
+            self.state = State::Failed;
+            return Command::default();
+        }
+        let error = angle_wrap(to_ball.y.atan2(to_ball.x) - robot.pose.orientation);
+        command.motion = MotionCommand::local_velocity(Vector3::new(
+            CAPTURE_SPEED * error.cos().max(0.0),
+            0.0,
+            ORIENTATION_GAIN * error,
+        ));
+        command
+    }
+}
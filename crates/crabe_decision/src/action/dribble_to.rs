@@ -0,0 +1,118 @@
+use crate::action::dribble::DRIBBLER_SPEED;
+use crate::action::move_to::{follow, MoveTo};
+use crate::action::state::State;
+use crate::action::Action;
+use crabe_framework::data::output::Command;
+use crabe_framework::data::tool::ToolData;
+use crabe_framework::data::world::World;
+use crabe_navigation::constant::{BALL_RADIUS, ROBOT_RADIUS};
+use crabe_navigation::motion_profile::{MotionProfile, Trapezoidal};
+use nalgebra::{Point2, Vector2};
+
+/// The distance in meters between the ball and the target below which the ball is placed,
+/// under the 0.15 meters allowed by the rules.
+pub const PLACEMENT_TOLERANCE: f64 = 0.1;
+/// The maximum speed in meters per second of the robot carrying the ball.
+const CARRY_SPEED: f64 = 0.5;
+/// The maximum acceleration and deceleration in m.s-2 of the robot carrying the ball.
+const CARRY_ACCELERATION: f64 = 0.5;
+/// The speed in meters per second below which the robot is considered stopped.
+const STOPPED_SPEED: f64 = 0.05;
+/// The distance in meters to the ball beyond which the ball is lost.
+const LOST_DISTANCE: f64 = 0.3;
+
+/// The `DribbleTo` struct represents an action that carries the ball held by the dribbler to
+/// a target, slowly enough not to lose it, and stops with the ball on the target.
+#[derive(Clone)]
+pub struct DribbleTo {
+    /// The current state of the action.
+    state: State,
+    /// The point the ball is carried to.
+    target: Point2<f64>,
+    /// The move computed for the previous order, kept so that it compensates the lag of the
+    /// measured velocity behind its last command.
+    move_to: Option<MoveTo>,
+}
+
+impl From<&mut DribbleTo> for DribbleTo {
+    fn from(other: &mut DribbleTo) -> DribbleTo {
+        DribbleTo {
+            state: other.state,
+            target: other.target,
+            move_to: other.move_to.clone(),
+        }
+    }
+}
+
+impl DribbleTo {
+    /// Creates a new `DribbleTo` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `target`: The point the ball is carried to.
+    pub fn new(target: Point2<f64>) -> Self {
+        Self {
+            state: State::Running,
+            target,
+            move_to: None,
+        }
+    }
+}
+
+impl Action for DribbleTo {
+    /// Returns the name of the action.
+    fn name(&self) -> String {
+        String::from("DribbleTo")
+    }
+
+    /// Returns the state of the action.
+    fn state(&mut self) -> State {
+        self.state
+    }
+
+    /// Computes the orders to move the robot so that the ball in front of it ends on the
+    /// target. The action is done once the ball is on the target and the robot stopped, and
+    /// fails if the ball is lost.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: The id of the robot for which the orders are computed.
+    /// * `world`: The current state of the world.
+    /// * `tools`: A collection of external tools used by the action, such as a viewer.
+    fn compute_order(&mut self, id: u8, world: &World, tools: &mut ToolData) -> Command {
+        let (Some(robot), Some(ball)) = (world.allies_bot.get(&id), &world.ball) else {
+            self.state = State::Failed;
+            return Command::default();
+        };
+        let ball = ball.position_2d();
+        if !robot.has_ball && (ball - robot.pose.position).norm() > LOST_DISTANCE {
+            self.state = State::Failed;
+            return Command::default();
+        }
+        let placed = (self.target - ball).norm() < PLACEMENT_TOLERANCE;
+        if placed && robot.velocity.linear.norm() < STOPPED_SPEED {
+            self.state = State::Done;
+            return Command {
+                dribbler: DRIBBLER_SPEED,
+                ..Default::default()
+            };
+        }
+
+        // Keep the current orientation on the last centimeters, where the direction to the
+        // target is not reliable.
+        let orientation = (self.target - ball)
+            .try_normalize(PLACEMENT_TOLERANCE / 2.0)
+            .map_or(robot.pose.orientation, |direction| {
+                direction.y.atan2(direction.x)
+            });
+        let front = Vector2::new(orientation.cos(), orientation.sin());
+        let position = self.target - front * (ROBOT_RADIUS + BALL_RADIUS);
+        let profile = MotionProfile {
+            linear: Trapezoidal::new(CARRY_SPEED, CARRY_ACCELERATION, CARRY_ACCELERATION),
+            ..Default::default()
+        };
+        let move_to =
+            MoveTo::new(position, orientation, DRIBBLER_SPEED, false, None).with_profile(profile);
+        follow(&mut self.move_to, move_to).compute_order(id, world, tools)
+    }
+}
@@ -0,0 +1,94 @@
+use crate::action::move_to::{follow, MoveTo};
+use crate::action::state::State;
+use crate::action::Action;
+use crabe_framework::data::output::Command;
+use crabe_framework::data::tool::ToolData;
+use crabe_framework::data::world::World;
+use crabe_navigation::constant::{BALL_RADIUS, ROBOT_RADIUS};
+use crabe_navigation::environment::Avoidance;
+use nalgebra::Point2;
+
+/// The distance in meters between the front of the robot and the ball once behind it.
+const APPROACH_MARGIN: f64 = 0.05;
+
+/// The `GoToBall` struct represents an action that places the robot behind the ball, facing
+/// the target the ball will be sent to. The robot goes around the ball without touching it.
+#[derive(Clone)]
+pub struct GoToBall {
+    /// The current state of the action.
+    state: State,
+    /// The point the ball will be sent to.
+    target: Point2<f64>,
+    /// The move computed for the previous order, kept so that it compensates the lag of the
+    /// measured velocity behind its last command.
+    move_to: Option<MoveTo>,
+}
+
+impl From<&mut GoToBall> for GoToBall {
+    fn from(other: &mut GoToBall) -> GoToBall {
+        GoToBall {
+            state: other.state,
+            target: other.target,
+            move_to: other.move_to.clone(),
+        }
+    }
+}
+
+impl GoToBall {
+    /// Creates a new `GoToBall` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `target`: The point the ball will be sent to.
+    pub fn new(target: Point2<f64>) -> Self {
+        Self {
+            state: State::Running,
+            target,
+            move_to: None,
+        }
+    }
+}
+
+impl Action for GoToBall {
+    /// Returns the name of the action.
+    fn name(&self) -> String {
+        String::from("GoToBall")
+    }
+
+    /// Returns the state of the action.
+    fn state(&mut self) -> State {
+        self.state
+    }
+
+    /// Computes the orders to move the robot behind the ball, on the line going from the
+    /// target through the ball. The action fails if the ball is not seen.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: The id of the robot for which the orders are computed.
+    /// * `world`: The current state of the world.
+    /// * `tools`: A collection of external tools used by the action, such as a viewer.
+    fn compute_order(&mut self, id: u8, world: &World, tools: &mut ToolData) -> Command {
+        let Some(ball) = &world.ball else {
+            self.state = State::Failed;
+            return Command::default();
+        };
+        let ball = ball.position_2d();
+        let Some(direction) = (self.target - ball).try_normalize(f64::EPSILON) else {
+            self.state = State::Failed;
+            return Command::default();
+        };
+
+        let approach = ball - direction * (ROBOT_RADIUS + BALL_RADIUS + APPROACH_MARGIN);
+        let move_to = MoveTo::new(approach, direction.y.atan2(direction.x), 0.0, true, None)
+            .with_avoidance(Avoidance {
+                avoid_penalty_areas: true,
+                touch_ball: false,
+                ..Default::default()
+            });
+        let move_to = follow(&mut self.move_to, move_to);
+        let command = move_to.compute_order(id, world, tools);
+        self.state = move_to.state();
+        command
+    }
+}
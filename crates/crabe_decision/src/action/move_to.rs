@@ -1,9 +1,14 @@
 use crate::action::state::State;
 use crate::action::Action;
-use crabe_framework::data::output::{Command, Kick};
+use crabe_framework::data::output::{Command, Kick, MotionCommand};
 use crabe_framework::data::tool::ToolData;
-use crabe_framework::data::world::{AllyInfo, Robot, World};
-use nalgebra::{Isometry2, Point2, Vector2, Vector3};
+use crabe_framework::data::world::World;
+use crabe_navigation::constant::ROBOT_RADIUS;
+use crabe_navigation::environment::{Avoidance, Environment};
+use crabe_navigation::motion_profile::MotionProfile;
+use crabe_navigation::path_planner::{PathPlanner, VisibilityGraph};
+use crabe_navigation::velocity_obstacle::{MovingObstacle, Orca};
+use nalgebra::{Point2, Vector2, Vector3};
 use std::f64::consts::PI;
 
 /// The `MoveTo` struct represents an action that moves the robot to a specific location on the field, with a given target orientation.
@@ -18,6 +23,21 @@ pub struct MoveTo {
     charge: bool,
     dribbler: f32,
     kicker: Option<Kick>,
+    /// What the robot must keep away from, in addition to the other robots.
+    avoidance: Avoidance,
+    /// The planner computing the path around the other robots.
+    planner: VisibilityGraph,
+    /// The collision avoidance adjusting the velocity to the motion of the other robots.
+    orca: Orca,
+    /// The limits of the velocity and of the acceleration of the robot.
+    profile: MotionProfile,
+    /// The distance in meters to the target below which the position is reached.
+    position_tolerance: f64,
+    /// The angle in radians to the target orientation below which the orientation is reached.
+    orientation_tolerance: f64,
+    /// The last velocity commanded, in the frame of the field, compensating the lag of the
+    /// measured velocity behind the command.
+    commanded: Option<Vector3<f64>>,
 }
 
 impl From<&mut MoveTo> for MoveTo {
@@ -29,6 +49,13 @@ impl From<&mut MoveTo> for MoveTo {
             charge: other.charge,
             dribbler: other.dribbler,
             kicker: other.kicker,
+            avoidance: other.avoidance,
+            planner: other.planner.clone(),
+            orca: other.orca.clone(),
+            profile: other.profile,
+            position_tolerance: other.position_tolerance,
+            orientation_tolerance: other.orientation_tolerance,
+            commanded: other.commanded,
         }
     }
 }
@@ -54,36 +81,74 @@ impl MoveTo {
             charge,
             dribbler,
             kicker,
+            avoidance: Avoidance::default(),
+            planner: VisibilityGraph::default(),
+            orca: Orca::default(),
+            profile: MotionProfile::default(),
+            position_tolerance: POSITION_TOLERANCE,
+            orientation_tolerance: ORIENTATION_TOLERANCE,
+            commanded: None,
         }
     }
-}
 
-fn frame(x: f64, y: f64, orientation: f64) -> Isometry2<f64> {
-    Isometry2::new(Vector2::new(x, y), orientation)
-}
+    /// Sets what the robot must keep away from while moving, such as the ball or the
+    /// penalty areas. By default only the other robots are avoided.
+    pub fn with_avoidance(mut self, avoidance: Avoidance) -> Self {
+        self.avoidance = avoidance;
+        self
+    }
+
+    /// Sets the limits of the velocity and of the acceleration of the robot.
+    pub fn with_profile(mut self, profile: MotionProfile) -> Self {
+        self.profile = profile;
+        self
+    }
+
+    /// Sets the distance in meters and the angle in radians to the target below which
+    /// the robot has arrived.
+    pub fn with_tolerances(mut self, position: f64, orientation: f64) -> Self {
+        self.position_tolerance = position;
+        self.orientation_tolerance = orientation;
+        self
+    }
 
-fn frame_inv(frame: Isometry2<f64>) -> Isometry2<f64> {
-    frame.inverse()
+    /// Replaces the target and the settings of the move by the ones of `other`, keeping the
+    /// last velocity commanded to compensate the lag of the measured velocity.
+    pub fn follow(&mut self, other: MoveTo) {
+        let commanded = self.commanded;
+        *self = other;
+        self.commanded = commanded;
+    }
 }
 
-fn robot_frame(robot: &Robot<AllyInfo>) -> Isometry2<f64> {
-    frame(
-        robot.pose.position.x,
-        robot.pose.position.y,
-        robot.pose.orientation,
-    )
+/// Updates the move kept in `slot` with `move_to`, or stores `move_to` if there is none, and
+/// returns it. Used by the actions computing a new move on every order.
+pub(crate) fn follow(slot: &mut Option<MoveTo>, move_to: MoveTo) -> &mut MoveTo {
+    let move_to = match slot.take() {
+        Some(mut current) => {
+            current.follow(move_to);
+            current
+        }
+        None => move_to,
+    };
+    slot.insert(move_to)
 }
 
-fn angle_wrap(alpha: f64) -> f64 {
-    (alpha + PI) % (2.0 * PI) - PI
+/// Returns the angle wrapped in `[-PI, PI)`.
+pub(crate) fn angle_wrap(alpha: f64) -> f64 {
+    (alpha + PI).rem_euclid(2.0 * PI) - PI
 }
 
-/// The default factor speed for the robot to move towards the target position.
-const GOTO_SPEED: f64 = 1.5;
-/// The default factor speed for the robot to rotate towards the target orientation.
-const GOTO_ROTATION: f64 = 1.5;
-/// The error tolerance for arriving at the target position.
-const ERR_TOLERANCE: f64 = 0.115;
+/// The default distance in meters to the target below which the position is reached.
+const POSITION_TOLERANCE: f64 = 0.03;
+/// The default angle in radians to the target below which the orientation is reached.
+const ORIENTATION_TOLERANCE: f64 = 0.05;
+/// The largest difference in m.s-1 between the linear velocity the motion profile starts
+/// from and the measured one.
+const LINEAR_LAG_TOLERANCE: f64 = 0.2;
+/// The largest difference in rad.s-1 between the angular velocity the motion profile starts
+/// from and the measured one.
+const ANGULAR_LAG_TOLERANCE: f64 = 0.5;
 
 impl Action for MoveTo {
     /// Returns the name of the action.
@@ -97,6 +162,12 @@ impl Action for MoveTo {
     }
 
     /// Computes the orders to be sent to the robot and returns a `Command` instance.
+    /// The robot follows the path planned around the obstacles, with the fastest velocity
+    /// from which it can still stop at the end of the path, and its velocity is adjusted to
+    /// avoid the moving robots. The motion profile is re-planned from the measured velocity,
+    /// moved towards the previous command by at most a small tolerance to compensate the lag
+    /// of the measure, so that a command clamped by the guards or a blocked robot does not
+    /// wind the profile up.
     /// If the robot arrives at the target position and orientation, the action is considered done.
     ///
     /// # Arguments
@@ -106,27 +177,56 @@ impl Action for MoveTo {
     /// * `tools`: A collection of external tools used by the action, such as a viewer.
     fn compute_order(&mut self, id: u8, world: &World, _tools: &mut ToolData) -> Command {
         if let Some(robot) = world.allies_bot.get(&id) {
-            let ti = frame_inv(robot_frame(robot));
-            let target_in_robot = ti * Point2::new(self.target.x, self.target.y);
-
             let error_orientation = angle_wrap(self.orientation - robot.pose.orientation);
-            let error_x = target_in_robot[0];
-            let error_y = target_in_robot[1];
-            let arrived = Vector3::new(error_x, error_y, error_orientation).norm() < ERR_TOLERANCE;
+            let arrived = (self.target - robot.pose.position).norm() < self.position_tolerance
+                && error_orientation.abs() < self.orientation_tolerance;
             if arrived {
                 self.state = State::Done;
             }
 
-            let order = Vector3::new(
-                GOTO_SPEED * error_x,
-                GOTO_SPEED * error_y,
-                GOTO_ROTATION * error_orientation,
+            let environment = Environment::from_world(id, world, &self.avoidance);
+            let path = self
+                .planner
+                .plan(robot.pose.position, self.target, &environment)
+                .unwrap_or_else(|| vec![self.target]);
+            let distance = path
+                .iter()
+                .fold(
+                    (robot.pose.position, 0.0),
+                    |(previous, distance), waypoint| {
+                        (*waypoint, distance + (waypoint - previous).norm())
+                    },
+                )
+                .1;
+            let (mut linear, mut angular) = (robot.velocity.linear, robot.velocity.angular);
+            if let Some(commanded) = self.commanded {
+                let lag = commanded.xy() - linear;
+                linear += lag * (LINEAR_LAG_TOLERANCE / lag.norm()).min(1.0);
+                angular +=
+                    (commanded.z - angular).clamp(-ANGULAR_LAG_TOLERANCE, ANGULAR_LAG_TOLERANCE);
+            }
+            let waypoint = path.first().copied().unwrap_or(self.target);
+            let preferred = (waypoint - robot.pose.position)
+                .try_normalize(f64::EPSILON)
+                .map_or(Vector2::zeros(), |direction| {
+                    self.profile.linear_velocity(direction, distance, linear)
+                });
+
+            let obstacles = MovingObstacle::from_world(id, world, &self.avoidance);
+            let velocity = self.orca.velocity(
+                robot.pose.position,
+                robot.velocity.linear,
+                ROBOT_RADIUS,
+                preferred,
+                self.profile.linear.max_velocity,
+                &obstacles,
             );
+            let angular_velocity = self.profile.angular_velocity(error_orientation, angular);
+            let commanded = Vector3::new(velocity.x, velocity.y, angular_velocity);
+            self.commanded = Some(commanded);
 
             Command {
-                forward_velocity: order.x as f32,
-                left_velocity: order.y as f32,
-                angular_velocity: order.z as f32,
+                motion: MotionCommand::global_velocity(commanded),
                 charge: self.charge,
                 kick: self.kicker,
                 dribbler: self.dribbler,
@@ -0,0 +1,80 @@
+use crate::action::aim_and_kick::AimAndKick;
+use crate::action::state::State;
+use crate::action::Action;
+use crate::pass::SharedContract;
+use chrono::Utc;
+use crabe_framework::data::output::Command;
+use crabe_framework::data::tool::ToolData;
+use crabe_framework::data::world::World;
+
+/// The `Pass` struct represents an action that aims at the receiving point of a pass contract
+/// with the ball on the dribbler, then kicks it at the speed of the contract.
+/// The kick time of the contract is updated when the ball is kicked.
+#[derive(Clone)]
+pub struct Pass {
+    /// The current state of the action.
+    state: State,
+    /// The contract shared with the receiver.
+    contract: SharedContract,
+    /// The action turning with the ball and kicking it.
+    aim: AimAndKick,
+}
+
+impl From<&mut Pass> for Pass {
+    fn from(other: &mut Pass) -> Pass {
+        Pass {
+            state: other.state,
+            contract: other.contract.clone(),
+            aim: other.aim.clone(),
+        }
+    }
+}
+
+impl Pass {
+    /// Creates a new `Pass` instance following the given contract.
+    pub fn new(contract: SharedContract) -> Self {
+        let aim = {
+            let contract = contract.borrow();
+            AimAndKick::new(contract.target).with_speed(contract.speed)
+        };
+        Self {
+            state: State::Running,
+            contract,
+            aim,
+        }
+    }
+}
+
+impl Action for Pass {
+    /// Returns the name of the action.
+    fn name(&self) -> String {
+        String::from("Pass")
+    }
+
+    /// Returns the state of the action.
+    fn state(&mut self) -> State {
+        self.state
+    }
+
+    /// Computes the orders to aim at the receiver and kick the ball.
+    /// The action is done once the ball left the robot after the kick, and fails if the ball
+    /// is lost before.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: The id of the robot for which the orders are computed.
+    /// * `world`: The current state of the world.
+    /// * `tools`: A collection of external tools used by the action, such as a viewer.
+    fn compute_order(&mut self, id: u8, world: &World, tools: &mut ToolData) -> Command {
+        let command = self.aim.compute_order(id, world, tools);
+        if command.kick.is_some() {
+            let mut contract = self.contract.borrow_mut();
+            if !contract.kicked {
+                contract.kicked = true;
+                contract.kick_time = Utc::now();
+            }
+        }
+        self.state = self.aim.state();
+        command
+    }
+}
@@ -0,0 +1,125 @@
+use crate::action::dribble::DRIBBLER_SPEED;
+use crate::action::move_to::{follow, MoveTo};
+use crate::action::state::State;
+use crate::action::Action;
+use crate::pass::SharedContract;
+use chrono::{Duration, Utc};
+use crabe_framework::data::output::Command;
+use crabe_framework::data::tool::ToolData;
+use crabe_framework::data::world::World;
+
+/// The speed in meters per second below which the ball is considered stopped.
+const STOPPED_SPEED: f64 = 0.1;
+/// The time in milliseconds after the expected kick, or after the expected arrival of the
+/// ball once kicked, after which the reception has failed.
+const RECEIVE_TIMEOUT: i64 = 1000;
+/// The time in milliseconds the ball may take to start moving after the kick.
+const KICK_DELAY: i64 = 200;
+/// The distance in meters to the ball beyond which a ball going away is lost.
+const LOST_DISTANCE: f64 = 0.5;
+
+/// The `Receive` struct represents an action that waits for the ball at the receiving point
+/// of a pass contract, then moves onto the line of the ball once kicked and catches it with
+/// the dribbler.
+#[derive(Clone)]
+pub struct Receive {
+    /// The current state of the action.
+    state: State,
+    /// The contract shared with the passer.
+    contract: SharedContract,
+    /// The move computed for the previous order, kept so that it compensates the lag of the
+    /// measured velocity behind its last command.
+    move_to: Option<MoveTo>,
+}
+
+impl From<&mut Receive> for Receive {
+    fn from(other: &mut Receive) -> Receive {
+        Receive {
+            state: other.state,
+            contract: other.contract.clone(),
+            move_to: other.move_to.clone(),
+        }
+    }
+}
+
+impl Receive {
+    /// Creates a new `Receive` instance following the given contract.
+    pub fn new(contract: SharedContract) -> Self {
+        Self {
+            state: State::Running,
+            contract,
+            move_to: None,
+        }
+    }
+}
+
+impl Action for Receive {
+    /// Returns the name of the action.
+    fn name(&self) -> String {
+        String::from("Receive")
+    }
+
+    /// Returns the state of the action.
+    fn state(&mut self) -> State {
+        self.state
+    }
+
+    /// Computes the orders to face the ball from the receiving point, then from the closest
+    /// point of the line of the ball once kicked, with the dribbler spinning.
+    /// The action is done once the robot holds the ball, and fails if the ball goes past the
+    /// robot, or is not kicked or does not arrive in time.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: The id of the robot for which the orders are computed.
+    /// * `world`: The current state of the world.
+    /// * `tools`: A collection of external tools used by the action, such as a viewer.
+    fn compute_order(&mut self, id: u8, world: &World, tools: &mut ToolData) -> Command {
+        let (Some(robot), Some(ball)) = (world.allies_bot.get(&id), &world.ball) else {
+            self.state = State::Failed;
+            return Command::default();
+        };
+        if robot.has_ball {
+            self.state = State::Done;
+            return Command {
+                dribbler: DRIBBLER_SPEED,
+                ..Default::default()
+            };
+        }
+
+        let contract = *self.contract.borrow();
+        let position = ball.position_2d();
+        let velocity = ball.velocity.xy();
+        let now = Utc::now();
+        let moving = velocity.norm() > STOPPED_SPEED;
+        if !contract.kicked && now > contract.kick_time + Duration::milliseconds(RECEIVE_TIMEOUT) {
+            // The passer failed to kick the ball in time.
+            self.state = State::Failed;
+            return Command::default();
+        }
+        let target = if !contract.kicked
+            || (!moving && now < contract.kick_time + Duration::milliseconds(KICK_DELAY))
+        {
+            contract.target
+        } else {
+            let deadline = contract.arrival_time() + Duration::milliseconds(RECEIVE_TIMEOUT);
+            let to_robot = robot.pose.position - position;
+            let along = to_robot.dot(&velocity) / velocity.norm().max(STOPPED_SPEED);
+            if !moving || now > deadline || (along < 0.0 && to_robot.norm() > LOST_DISTANCE) {
+                self.state = State::Failed;
+                return Command::default();
+            }
+            position + velocity.normalize() * along.max(0.0)
+        };
+
+        let facing = position - target;
+        let move_to = MoveTo::new(
+            target,
+            facing.y.atan2(facing.x),
+            DRIBBLER_SPEED,
+            false,
+            None,
+        );
+        follow(&mut self.move_to, move_to).compute_order(id, world, tools)
+    }
+}
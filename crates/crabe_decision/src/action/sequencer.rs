@@ -40,9 +40,22 @@ impl Sequencer {
     }
 
     /// Removes all the actions from the sequence and cancels their execution.
+    /// The sequence can then run new actions, even if it failed.
     pub fn clear(&mut self) {
         self.actions.iter_mut().for_each(|a| a.cancel());
         self.actions.clear();
+        self.state = State::Running;
+    }
+
+    /// Returns whether the sequence has no action left to execute, because they are all
+    /// done or one of them failed.
+    pub fn is_idle(&self) -> bool {
+        self.state == State::Failed || self.actions.is_empty()
+    }
+
+    /// Returns whether one of the actions of the sequence failed.
+    pub fn has_failed(&self) -> bool {
+        self.state == State::Failed
     }
 
     /// Returns the name of the sequence, which is a concatenation of the names of its actions.
@@ -3,7 +3,7 @@
 //! This crate implements the main system AI for Robocup SSL Games using the
 //! Manager-Strategies-Actions architecture.
 //!
-//! It includes several modules, such as action, manager, pipeline, and strategy.
+//! It includes several modules, such as action, manager, pass, pipeline, role, and strategy.
 
 /// The `action` module contains the definitions of various actions that can be
 /// performed by a robot, such as moving to a certain point.
@@ -12,12 +12,18 @@ pub mod action;
 /// It contains multiple manager implementation, which is in charge of managing
 /// the execution of the strategies.
 pub mod manager;
+/// The `pass` module scores the passes between the robots and defines the contract shared
+/// by the robot passing the ball and the robot receiving it.
+pub mod pass;
 /// The pipeline module contains the DecisionPipeline struct, which defines the pipeline
 /// for making decisions for a SSL robot fleet based on the filtered input data.
 /// The pipeline follows a Manager-Strategies-Actions architecture, where the Manager is
 /// responsible for coordinating and executing the Strategies,
 /// which in turn use `Action` to issue actions for each robots.
 pub mod pipeline;
+/// The `role` module defines the roles the strategies need, such as goalkeeper or attacker,
+/// and assigns the ally robots to them.
+pub mod role;
 /// The strategy module contains the Strategy trait and various implementations of strategies.
 /// Strategies are behaviors that one or multiple robots can adopt in order to achieve a certain goal.
 pub mod strategy;
@@ -0,0 +1,83 @@
+use crate::action::ActionWrapper;
+use crate::manager::play_book::PlayBook;
+use crate::manager::Manager;
+use crate::role::RoleAssigner;
+use crate::strategy::Strategy;
+use crabe_framework::data::tool::ToolData;
+use crabe_framework::data::world::{GameState, World};
+use log::info;
+
+/// The `GameManager` struct represents a decision manager that obeys the referee.
+/// Each time the state of the game changes, the actions of the robots are cleared and the
+/// strategies of the play book for the new state are started.
+///
+/// The robots are assigned to the roles of the strategies at each step, so that a robot
+/// leaving the field is replaced.
+pub struct GameManager {
+    play_book: Box<dyn PlayBook>,
+    /// The state of the game the current strategies were chosen for.
+    state: Option<GameState>,
+    /// The strategies of the current state, `None` once finished so that the assigned roles
+    /// keep their index.
+    strategies: Vec<Option<Box<dyn Strategy>>>,
+    role_assigner: RoleAssigner,
+}
+
+impl GameManager {
+    /// Creates a new `GameManager` instance following the given play book.
+    pub fn new(play_book: Box<dyn PlayBook>) -> Self {
+        Self {
+            play_book,
+            state: None,
+            strategies: vec![],
+            role_assigner: RoleAssigner::default(),
+        }
+    }
+}
+
+impl Manager for GameManager {
+    /// Switches the strategies when the state of the game changes, then executes them on the
+    /// given `World` data, `ToolData`, and `ActionWrapper`.
+    fn step(
+        &mut self,
+        world: &World,
+        tools_data: &mut ToolData,
+        action_wrapper: &mut ActionWrapper,
+    ) {
+        let state = world.data.state;
+        if self.state != Some(state) {
+            self.strategies = self
+                .play_book
+                .strategies(&state, world)
+                .into_iter()
+                .map(Some)
+                .collect();
+            info!(
+                "{:?}: {} play book runs {}",
+                state,
+                self.play_book.name(),
+                self.strategies
+                    .iter()
+                    .flatten()
+                    .map(|s| s.name())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+            action_wrapper.clear_all();
+            self.role_assigner = RoleAssigner::default();
+            self.state = Some(state);
+        }
+
+        for id in self.role_assigner.assign(world, &mut self.strategies) {
+            action_wrapper.clear(id);
+        }
+        for strategy in self.strategies.iter_mut() {
+            if strategy
+                .as_mut()
+                .is_some_and(|s| s.step(world, tools_data, action_wrapper))
+            {
+                *strategy = None;
+            }
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use crate::strategy::Strategy;
+use crabe_framework::data::world::{GameState, World};
+
+mod standard;
+pub use self::standard::Standard;
+mod testing;
+pub use self::testing::Testing;
+
+/// The `PlayBook` trait gives the strategies the `GameManager` runs in each state of the game.
+/// Each play book is a different way to play the same match.
+pub trait PlayBook {
+    /// Name of the play book, that we use as simple reference
+    fn name(&self) -> &'static str;
+
+    /// Returns the strategies to run when the game enters the given state.
+    ///
+    /// # Arguments
+    ///
+    /// * `state`: The new state of the game, as decoded from the referee commands.
+    /// * `world`: The current state of the world.
+    fn strategies(&self, state: &GameState, world: &World) -> Vec<Box<dyn Strategy>>;
+}
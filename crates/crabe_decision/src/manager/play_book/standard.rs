@@ -0,0 +1,87 @@
+use crate::manager::play_book::PlayBook;
+use crate::strategy::attack::Attacker;
+use crate::strategy::basic::Halt;
+use crate::strategy::defense::{Goalkeeper, ManMarking, Wall};
+use crate::strategy::set_piece::{
+    BallPlacer, KeepOutOfPlacement, KickTaker, KickoffFormation, PenaltyFormation, PenaltyKeeper,
+    PenaltyShooter,
+};
+use crate::strategy::Strategy;
+use crabe_framework::data::world::{GameState, Restart, World};
+
+/// The maximum number of robots in the wall protecting the ally goal.
+const WALL_SIZE: usize = 2;
+/// The maximum number of enemy robots marked.
+const MARKERS: usize = 1;
+/// The maximum number of robots in the wall protecting the ally goal from a free kick.
+const FREE_KICK_WALL_SIZE: usize = 3;
+/// The maximum number of enemy robots marked during an enemy free kick.
+const FREE_KICK_MARKERS: usize = 2;
+/// The maximum number of robots waiting behind the ball during a penalty kick.
+const PENALTY_FORMATION_SIZE: usize = 5;
+
+/// The `Standard` play book is the one used during official matches.
+///
+/// The set pieces are played by dedicated strategies, depending on the team which restarts
+/// the game. The states of the game without a strategy written for them stop the robots.
+#[derive(Default)]
+pub struct Standard;
+
+impl PlayBook for Standard {
+    fn name(&self) -> &'static str {
+        "Standard"
+    }
+
+    fn strategies(&self, state: &GameState, world: &World) -> Vec<Box<dyn Strategy>> {
+        let ally = world.team_color;
+        match *state {
+            GameState::Halt | GameState::Stop => vec![Box::new(Halt::new())],
+            GameState::BallPlacement(team) if team == ally => vec![Box::new(BallPlacer::new())],
+            GameState::BallPlacement(_) => vec![Box::new(KeepOutOfPlacement::new())],
+            GameState::PrepareKickoff(team) => vec![
+                Box::new(Goalkeeper::new()),
+                Box::new(KickoffFormation::new(team == ally)),
+            ],
+            GameState::NormalStart(Restart::Kickoff(team)) if team == ally => vec![
+                Box::new(Goalkeeper::new()),
+                Box::new(KickTaker::new()),
+                Box::new(KickoffFormation::new(false)),
+            ],
+            GameState::NormalStart(Restart::Kickoff(_)) => vec![
+                Box::new(Goalkeeper::new()),
+                Box::new(KickoffFormation::new(false)),
+            ],
+            GameState::PreparePenalty(team) | GameState::NormalStart(Restart::Penalty(team))
+                if team == ally =>
+            {
+                let shoot = matches!(state, GameState::NormalStart(_));
+                vec![
+                    Box::new(Goalkeeper::new()),
+                    Box::new(PenaltyShooter::new(shoot)),
+                    Box::new(PenaltyFormation::new(PENALTY_FORMATION_SIZE, true)),
+                ]
+            }
+            GameState::PreparePenalty(_) | GameState::NormalStart(Restart::Penalty(_)) => vec![
+                Box::new(PenaltyKeeper::new()),
+                Box::new(PenaltyFormation::new(PENALTY_FORMATION_SIZE, false)),
+            ],
+            GameState::FreeKick(team) if team == ally => vec![
+                Box::new(Goalkeeper::new()),
+                Box::new(KickTaker::new()),
+                Box::new(Wall::new(WALL_SIZE)),
+                Box::new(ManMarking::new(MARKERS)),
+            ],
+            GameState::FreeKick(_) => vec![
+                Box::new(Goalkeeper::new()),
+                Box::new(Wall::new(FREE_KICK_WALL_SIZE)),
+                Box::new(ManMarking::new(FREE_KICK_MARKERS)),
+            ],
+            GameState::ForceStart | GameState::Running => vec![
+                Box::new(Goalkeeper::new()),
+                Box::new(Attacker::new()),
+                Box::new(Wall::new(WALL_SIZE)),
+                Box::new(ManMarking::new(MARKERS)),
+            ],
+        }
+    }
+}
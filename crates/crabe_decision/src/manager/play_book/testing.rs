@@ -0,0 +1,23 @@
+use crate::manager::play_book::PlayBook;
+use crate::strategy::basic::Halt;
+use crate::strategy::testing::Square;
+use crate::strategy::Strategy;
+use crabe_framework::data::world::{GameState, World};
+
+/// The `Testing` play book runs the testing strategies while the game is running, and stops
+/// the robots otherwise. It's used to test strategies with a game controller.
+#[derive(Default)]
+pub struct Testing;
+
+impl PlayBook for Testing {
+    fn name(&self) -> &'static str {
+        "Testing"
+    }
+
+    fn strategies(&self, state: &GameState, _world: &World) -> Vec<Box<dyn Strategy>> {
+        match state {
+            GameState::Running | GameState::ForceStart => vec![Box::new(Square::new(0))],
+            _ => vec![Box::new(Halt::new())],
+        }
+    }
+}
@@ -0,0 +1,273 @@
+use crate::action::aim_and_kick::{kick_speed, BALL_DECELERATION};
+use chrono::{DateTime, Duration, Utc};
+use crabe_framework::data::geometry::Area;
+use crabe_framework::data::world::World;
+use crabe_navigation::constant::{BALL_RADIUS, MAX_LINEAR_SPEED, ROBOT_RADIUS};
+use crabe_navigation::environment::distance_to_segment;
+use nalgebra::{Point2, Vector2};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// The number of points of the enemy goal line checked for a shot.
+const GOAL_SAMPLES: usize = 21;
+/// The distance in meters between the posts and the first point checked for a shot.
+const POST_MARGIN: f64 = 0.05;
+/// The speed in meters per second a pass must still have when reaching the receiver.
+pub const RECEIVE_SPEED: f64 = 1.5;
+/// The shortest pass in meters worth playing.
+const MIN_PASS_DISTANCE: f64 = 1.0;
+/// The longest pass in meters worth playing.
+pub const MAX_PASS_DISTANCE: f64 = 6.0;
+/// The distance in meters between two points of the pass checked for an interception.
+const INTERCEPTION_STEP: f64 = 0.2;
+/// The maximum speed in meters per second of an enemy robot going to intercept the ball.
+const ENEMY_SPEED: f64 = 2.0;
+/// The time in seconds an enemy robot needs to react to a pass.
+const ENEMY_REACTION_TIME: f64 = 0.2;
+/// The time in seconds over which the motion of the enemy robots is extrapolated.
+const PREDICTION_HORIZON: f64 = 0.5;
+/// The margin in seconds between the ball and an enemy robot reaching a point of the pass
+/// for which the pass is considered half safe.
+const INTERCEPTION_TIME_SCALE: f64 = 0.15;
+/// The weight of the shot opportunity from the receiving point, against the progress
+/// towards the enemy goal.
+const SHOT_WEIGHT: f64 = 0.6;
+/// The part of the score lost by the longest passes.
+const DISTANCE_PENALTY: f64 = 0.3;
+/// The distance in meters between two candidate receiving points.
+const CANDIDATE_STEP: f64 = 0.5;
+/// The distance in meters between the candidate receiving points and the field lines.
+const CANDIDATE_MARGIN: f64 = 0.3;
+/// The time in milliseconds the passer is expected to need to capture the ball and aim.
+const AIM_DURATION: i64 = 1000;
+
+/// The `PassContract` struct is the agreement between the robot passing the ball and the
+/// robot receiving it, shared by the `Pass` and `Receive` actions.
+#[derive(Clone, Copy, Debug)]
+pub struct PassContract {
+    /// The id of the robot passing the ball.
+    pub passer: u8,
+    /// The id of the robot receiving the ball.
+    pub receiver: u8,
+    /// The point the ball is kicked from.
+    pub origin: Point2<f64>,
+    /// The point where the ball is received.
+    pub target: Point2<f64>,
+    /// The speed in meters per second of the ball when kicked.
+    pub speed: f64,
+    /// The time the ball is kicked at, planned until the kick and then actual.
+    pub kick_time: DateTime<Utc>,
+    /// Whether the ball was kicked.
+    pub kicked: bool,
+}
+
+/// A `PassContract` shared between the actions of the passer and of the receiver.
+pub type SharedContract = Rc<RefCell<PassContract>>;
+
+impl PassContract {
+    /// Creates a contract for a pass from `ball` to `target`, planned at `kick_time`, with the
+    /// speed for the ball to reach the receiver at the receiving speed.
+    pub fn new(
+        passer: u8,
+        receiver: u8,
+        ball: Point2<f64>,
+        target: Point2<f64>,
+        kick_time: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            passer,
+            receiver,
+            origin: ball,
+            target,
+            speed: kick_speed((target - ball).norm(), RECEIVE_SPEED),
+            kick_time,
+            kicked: false,
+        }
+    }
+
+    /// Wraps the contract so that it can be shared by the passer and the receiver.
+    pub fn shared(self) -> SharedContract {
+        Rc::new(RefCell::new(self))
+    }
+
+    /// Returns the time the ball is expected to reach the target.
+    pub fn arrival_time(&self) -> DateTime<Utc> {
+        let time = travel_time((self.target - self.origin).norm(), self.speed).unwrap_or(0.0);
+        self.kick_time + Duration::milliseconds((time * 1000.0) as i64)
+    }
+}
+
+/// Returns the time the robot at `position` is expected to kick the ball at `ball`, after
+/// going to it at full speed and aiming.
+pub fn expected_kick_time(position: &Point2<f64>, ball: &Point2<f64>) -> DateTime<Utc> {
+    let approach = (ball - position).norm() / MAX_LINEAR_SPEED;
+    Utc::now() + Duration::milliseconds((approach * 1000.0) as i64 + AIM_DURATION)
+}
+
+/// Returns the time in seconds the ball kicked at `speed` takes to travel `distance` meters,
+/// or `None` if it stops before.
+pub fn travel_time(distance: f64, speed: f64) -> Option<f64> {
+    let discriminant = speed.powi(2) - 2.0 * BALL_DECELERATION * distance;
+    (discriminant >= 0.0).then(|| (speed - discriminant.sqrt()) / BALL_DECELERATION)
+}
+
+/// Returns whether the ball can travel from `start` to `end` without hitting an enemy robot.
+pub fn is_open(world: &World, start: &Point2<f64>, end: &Point2<f64>) -> bool {
+    world.enemies_bot.values().all(|robot| {
+        distance_to_segment(&robot.pose.position, start, end) > ROBOT_RADIUS + BALL_RADIUS
+    })
+}
+
+/// Returns the points of the enemy goal line checked for a shot, from one post to the other.
+fn goal_samples(world: &World) -> Vec<Point2<f64>> {
+    let goal = &world.geometry.enemy_goal;
+    let center = goal.center();
+    let half_width = goal.width / 2.0 - POST_MARGIN;
+    (0..GOAL_SAMPLES)
+        .map(|i| {
+            let ratio = i as f64 / (GOAL_SAMPLES - 1) as f64;
+            center + Vector2::new(0.0, half_width * (2.0 * ratio - 1.0))
+        })
+        .collect()
+}
+
+/// Returns the middle of the widest section of the enemy goal line the ball can reach from
+/// `ball` without hitting an enemy robot, or the center of the goal if it is fully covered.
+pub fn open_goal_target(world: &World, ball: &Point2<f64>) -> Point2<f64> {
+    let samples = goal_samples(world);
+
+    // The first and last index of the widest run of open samples.
+    let mut widest: Option<(usize, usize)> = None;
+    let mut start = None;
+    for (index, sample) in samples.iter().enumerate() {
+        if !is_open(world, ball, sample) {
+            start = None;
+            continue;
+        }
+        let first = *start.get_or_insert(index);
+        if widest.is_none_or(|(a, b)| index - first > b - a) {
+            widest = Some((first, index));
+        }
+    }
+    widest.map_or(world.geometry.enemy_goal.center(), |(first, last)| {
+        nalgebra::center(&samples[first], &samples[last])
+    })
+}
+
+/// Returns the part of the enemy goal line, between 0 and 1, the ball can reach from `point`
+/// without hitting an enemy robot.
+pub fn shot_opening(world: &World, point: &Point2<f64>) -> f64 {
+    let samples = goal_samples(world);
+    let open = samples
+        .iter()
+        .filter(|sample| is_open(world, point, sample))
+        .count();
+    open as f64 / samples.len() as f64
+}
+
+/// Returns the probability-like safety, between 0 and 1, of the ball kicked at `speed`
+/// from `start` to `end` against the enemy robots intercepting it.
+///
+/// For points along the pass, the time the ball takes to reach the point is compared to the
+/// time each enemy robot takes to reach it, from its position extrapolated with its velocity.
+fn interception_safety(world: &World, start: &Point2<f64>, end: &Point2<f64>, speed: f64) -> f64 {
+    let pass = end - start;
+    let length = pass.norm();
+    let steps = (length / INTERCEPTION_STEP).ceil().max(1.0) as usize;
+    let mut safety: f64 = 1.0;
+    for step in 0..=steps {
+        let distance = length * step as f64 / steps as f64;
+        let point = start + pass * (distance / length.max(f64::EPSILON));
+        let Some(ball_time) = travel_time(distance, speed) else {
+            return 0.0;
+        };
+        for enemy in world.enemies_bot.values() {
+            let predicted =
+                enemy.pose.position + enemy.velocity.linear * ball_time.min(PREDICTION_HORIZON);
+            let reach = ((point - predicted).norm() - ROBOT_RADIUS - BALL_RADIUS).max(0.0);
+            let enemy_time = reach / ENEMY_SPEED + ENEMY_REACTION_TIME;
+            let margin = enemy_time - ball_time;
+            safety = safety.min(1.0 / (1.0 + (-margin / INTERCEPTION_TIME_SCALE).exp()));
+        }
+    }
+    safety
+}
+
+/// Returns the score, between 0 and 1, of a pass from `start` to `end`.
+///
+/// The score is the safety of the pass against an interception, weighted by the shot
+/// opportunity and the progress towards the enemy goal from the receiving point, and by a
+/// penalty for the long passes.
+pub fn evaluate(world: &World, start: &Point2<f64>, end: &Point2<f64>) -> f64 {
+    let distance = (end - start).norm();
+    if !(MIN_PASS_DISTANCE..=MAX_PASS_DISTANCE).contains(&distance) {
+        return 0.0;
+    }
+
+    let speed = kick_speed(distance, RECEIVE_SPEED);
+    let safety = interception_safety(world, start, end, speed);
+    let enemy_goal = world.geometry.enemy_goal.center();
+    let progress = (1.0 - (enemy_goal - end).norm() / world.geometry.field.length).max(0.0);
+    let opportunity = SHOT_WEIGHT * shot_opening(world, end) + (1.0 - SHOT_WEIGHT) * progress;
+    safety * opportunity * (1.0 - DISTANCE_PENALTY * distance / MAX_PASS_DISTANCE)
+}
+
+/// Returns the best point of the field to receive a pass from `start` with its score, among
+/// a grid of points outside of the penalty areas and within `max_distance` meters.
+pub fn best_target(
+    world: &World,
+    start: &Point2<f64>,
+    max_distance: f64,
+) -> Option<(Point2<f64>, f64)> {
+    best_target_where(world, start, max_distance, |_| true)
+}
+
+/// Returns the best point of the ally half of the field to receive a pass from `start` with
+/// its score, like [`best_target`]. The robots must stay in their half until the ball is in
+/// play after a kickoff.
+pub fn best_ally_half_target(
+    world: &World,
+    start: &Point2<f64>,
+    max_distance: f64,
+) -> Option<(Point2<f64>, f64)> {
+    let side = world.geometry.ally_goal.center().x.signum();
+    best_target_where(world, start, max_distance, |point| {
+        point.x * side > ROBOT_RADIUS
+    })
+}
+
+/// Returns the best point to receive a pass from `start` with its score, among the points
+/// of the grid accepted by `accept`.
+fn best_target_where(
+    world: &World,
+    start: &Point2<f64>,
+    max_distance: f64,
+    accept: impl Fn(&Point2<f64>) -> bool,
+) -> Option<(Point2<f64>, f64)> {
+    let geometry = &world.geometry;
+    let half_length = geometry.field.length / 2.0 - CANDIDATE_MARGIN;
+    let half_width = geometry.field.width / 2.0 - CANDIDATE_MARGIN;
+    let areas = [
+        Area::from_penalty(&geometry.ally_penalty, CANDIDATE_MARGIN),
+        Area::from_penalty(&geometry.enemy_penalty, CANDIDATE_MARGIN),
+    ];
+    let columns = (2.0 * half_length / CANDIDATE_STEP) as usize;
+    let rows = (2.0 * half_width / CANDIDATE_STEP) as usize;
+
+    (0..=columns)
+        .flat_map(|column| (0..=rows).map(move |row| (column, row)))
+        .map(|(column, row)| {
+            Point2::new(
+                -half_length + column as f64 * CANDIDATE_STEP,
+                -half_width + row as f64 * CANDIDATE_STEP,
+            )
+        })
+        .filter(|point| {
+            (point - start).norm() <= max_distance
+                && !areas.iter().any(|area| area.contains(point))
+                && accept(point)
+        })
+        .map(|point| (point, evaluate(world, start, &point)))
+        .filter(|(_, score)| *score > 0.0)
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+}
@@ -1,16 +1,43 @@
 use crate::action::ActionWrapper;
+use crate::manager::game_manager::GameManager;
 use crate::manager::manual::Manual;
+use crate::manager::play_book::{PlayBook, Standard, Testing};
 use crate::manager::Manager;
-use clap::Args;
+use clap::{Args, ValueEnum};
 use crabe_framework::component::{Component, DecisionComponent};
 use crabe_framework::config::CommonConfig;
 use crabe_framework::data::output::CommandMap;
 use crabe_framework::data::tool::ToolData;
 use crabe_framework::data::world::World;
 
+/// The managers the decision pipeline can run.
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum ManagerKind {
+    /// Run the testing strategies regardless of the referee.
+    Manual,
+    /// Switch strategies according to the referee, following the play book.
+    Game,
+}
+
+/// The play books the game manager can follow.
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum PlayBookKind {
+    /// The play book used during official matches.
+    Standard,
+    /// Run the testing strategies while the game is running.
+    Testing,
+}
+
 /// The `DecisionConfig` struct is used to hold configuration options for the decision pipeline.
 #[derive(Args)]
-pub struct DecisionConfig {}
+pub struct DecisionConfig {
+    /// The manager choosing the strategies of the robots.
+    #[arg(long, value_enum, default_value_t = ManagerKind::Manual)]
+    pub manager: ManagerKind,
+    /// The play book followed by the game manager.
+    #[arg(long, value_enum, default_value_t = PlayBookKind::Standard)]
+    pub play_book: PlayBookKind,
+}
 
 /// The `DecisionPipeline` struct represents the decision-making pipeline used by the robot.
 /// It consists of an action wrapper and a manager, both of which can be customized
@@ -24,10 +51,21 @@ pub struct DecisionPipeline {
 
 impl DecisionPipeline {
     /// Creates a new `DecisionPipeline` instance with the given configuration and common configuration options.
-    pub fn with_config(_decision_cfg: DecisionConfig, _common_cfg: &CommonConfig) -> Self {
+    pub fn with_config(decision_cfg: DecisionConfig, _common_cfg: &CommonConfig) -> Self {
+        let manager: Box<dyn Manager> = match decision_cfg.manager {
+            ManagerKind::Manual => Box::new(Manual::new()),
+            ManagerKind::Game => {
+                let play_book: Box<dyn PlayBook> = match decision_cfg.play_book {
+                    PlayBookKind::Standard => Box::new(Standard),
+                    PlayBookKind::Testing => Box::new(Testing),
+                };
+                Box::new(GameManager::new(play_book))
+            }
+        };
+
         Self {
             action_wrapper: ActionWrapper::default(),
-            manager: Box::new(Manual::new()),
+            manager,
         }
     }
 }
@@ -0,0 +1,187 @@
+/// The `hungarian` module solves the assignment of the robots to the roles.
+mod hungarian;
+pub use self::hungarian::hungarian;
+
+use crate::strategy::Strategy;
+use crabe_framework::data::world::{AllyInfo, Robot, World};
+use log::info;
+use nalgebra::Point2;
+use std::collections::HashMap;
+
+/// The cost of a robot that cannot take a role.
+const FORBIDDEN_COST: f64 = 1e6;
+/// The cost in meters removed for the robot which already had the role, so that the roles are
+/// not exchanged each time two robots are at similar distances.
+const ROLE_HYSTERESIS: f64 = 0.5;
+/// The distance in meters between the ally goal and the defenders.
+const DEFENSE_DISTANCE: f64 = 1.5;
+/// The distance in meters towards the enemy goal between the ball and the supporter.
+const SUPPORT_DISTANCE: f64 = 1.5;
+
+/// The `Role` enum represents the part a robot plays in a strategy. Each role has a cost
+/// for each robot, the lowest cost robots taking the roles.
+///
+/// Roles are listed by priority: when there are not enough robots on the field, the last
+/// roles are left empty.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Role {
+    /// The robot designated as goalkeeper to the referee, defending the ally goal.
+    Goalkeeper,
+    /// The robot playing the ball.
+    Attacker,
+    /// A robot standing between the ball and the ally goal.
+    Defender,
+    /// A robot placing itself to receive a pass.
+    Support,
+}
+
+impl Role {
+    /// Returns the cost for the robot to take the role, which is the distance to the position
+    /// of the role in meters.
+    ///
+    pub fn cost(&self, robot: &Robot<AllyInfo>, world: &World) -> f64 {
+        let geometry = &world.geometry;
+        let ball = world
+            .ball
+            .as_ref()
+            .map_or(geometry.center.center, |ball| ball.position_2d());
+        let target = match self {
+            Role::Goalkeeper => {
+                return if robot.id == world.data.ally.goalkeeper {
+                    0.0
+                } else {
+                    FORBIDDEN_COST
+                };
+            }
+            Role::Attacker => ball,
+            Role::Defender => {
+                let goal = geometry.ally_goal.center();
+                goal + (ball - goal)
+                    .try_normalize(f64::EPSILON)
+                    .unwrap_or_default()
+                    * DEFENSE_DISTANCE
+            }
+            Role::Support => {
+                let enemy_goal = geometry.enemy_goal.center();
+                let forward = (enemy_goal.x - ball.x).signum();
+                Point2::new(
+                    ball.x + forward * SUPPORT_DISTANCE,
+                    -ball.y.signum() * geometry.field.width / 4.0,
+                )
+            }
+        };
+        (robot.pose.position - target).norm()
+    }
+}
+
+/// The `RoleAssigner` struct gives the ally robots to the roles the strategies need, with the
+/// Hungarian algorithm minimizing the total cost of the assignment.
+///
+/// The assignment is computed again at each step, so that the robots leaving the field are
+/// replaced, and favors the previous assignment to avoid exchanging roles continuously.
+#[derive(Default)]
+pub struct RoleAssigner {
+    /// The robot which had each role, identified by the index of the strategy and the index
+    /// of the role in the strategy.
+    previous: HashMap<(usize, usize), u8>,
+}
+
+impl RoleAssigner {
+    /// Assigns the ally robots to the roles of the strategies, and gives the strategies their
+    /// robots.
+    ///
+    /// Returns the robots which changed strategy or left every strategy, whose actions must
+    /// be cleared.
+    pub fn assign(
+        &mut self,
+        world: &World,
+        strategies: &mut [Option<Box<dyn Strategy>>],
+    ) -> Vec<u8> {
+        let mut robots: Vec<&Robot<AllyInfo>> = world.allies_bot.values().collect();
+        robots.sort_by_key(|robot| robot.id);
+
+        let strategy_roles: Vec<Vec<Role>> = strategies
+            .iter()
+            .map(|strategy| strategy.as_ref().map_or(vec![], |s| s.roles(world)))
+            .collect();
+        let roles_count: Vec<usize> = strategy_roles.iter().map(Vec::len).collect();
+        let mut roles: Vec<((usize, usize), Role)> = strategy_roles
+            .into_iter()
+            .enumerate()
+            .flat_map(|(index, roles)| {
+                roles
+                    .into_iter()
+                    .enumerate()
+                    .map(move |(slot, role)| ((index, slot), role))
+            })
+            .collect();
+        // A role no robot can take, such as a goalkeeper not on the field, stays empty.
+        roles.retain(|(_, role)| {
+            robots
+                .iter()
+                .any(|robot| role.cost(robot, world) < FORBIDDEN_COST)
+        });
+        roles.sort_by_key(|(key, role)| (*role, key.1, key.0));
+        roles.truncate(robots.len());
+
+        let costs: Vec<Vec<f64>> = roles
+            .iter()
+            .map(|(key, role)| {
+                robots
+                    .iter()
+                    .map(|robot| {
+                        // A robot with an invalid pose, such as NaN, cannot take a role.
+                        let cost = Some(role.cost(robot, world))
+                            .filter(|cost| cost.is_finite())
+                            .unwrap_or(FORBIDDEN_COST);
+                        if self.previous.get(key) == Some(&robot.id) {
+                            cost - ROLE_HYSTERESIS
+                        } else {
+                            cost
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let mut assignment: HashMap<(usize, usize), u8> = HashMap::new();
+        for (row, column) in hungarian(&costs).into_iter().enumerate() {
+            if costs[row][column] < FORBIDDEN_COST - ROLE_HYSTERESIS {
+                assignment.insert(roles[row].0, robots[column].id);
+            }
+        }
+
+        let owner = |assignment: &HashMap<(usize, usize), u8>, id: u8| {
+            assignment
+                .iter()
+                .find(|(_, robot)| **robot == id)
+                .map(|(key, _)| key.0)
+        };
+        let mut released: Vec<u8> = self
+            .previous
+            .values()
+            .copied()
+            .filter(|id| owner(&self.previous, *id) != owner(&assignment, *id))
+            .collect();
+        released.sort();
+        released.dedup();
+        if !released.is_empty() {
+            info!("roles reassigned for robots {:?}", released);
+        }
+
+        for (index, strategy) in strategies.iter_mut().enumerate() {
+            let Some(strategy) = strategy else {
+                continue;
+            };
+            let slots = roles_count[index];
+            strategy.set_ids(
+                (0..slots)
+                    .map(|slot| assignment.get(&(index, slot)).copied())
+                    .collect(),
+            );
+        }
+
+        self.previous = assignment;
+        released
+    }
+}
@@ -0,0 +1,123 @@
+/// Solves the assignment problem with the Hungarian algorithm in `O(n²m)`.
+///
+/// `costs` has one row per task and one column per worker, with at least as many workers as
+/// tasks. Returns for each task the index of the worker assigned to it, such that the sum of
+/// the costs is minimal. The costs must be finite.
+pub fn hungarian(costs: &[Vec<f64>]) -> Vec<usize> {
+    let n = costs.len();
+    if n == 0 {
+        return vec![];
+    }
+    let m = costs[0].len();
+    assert!(n <= m, "more tasks than workers");
+    debug_assert!(
+        costs.iter().flatten().all(|cost| cost.is_finite()),
+        "non-finite cost"
+    );
+
+    // Potentials of the tasks and the workers, and the task assigned to each worker.
+    // Index 0 is a fictitious worker used as the root of the augmenting paths.
+    let mut u = vec![0.0; n + 1];
+    let mut v = vec![0.0; m + 1];
+    let mut task = vec![0; m + 1];
+    let mut way = vec![0; m + 1];
+    for i in 1..=n {
+        task[0] = i;
+        let mut j0 = 0;
+        let mut min_reduced = vec![f64::INFINITY; m + 1];
+        let mut used = vec![false; m + 1];
+        loop {
+            used[j0] = true;
+            let i0 = task[j0];
+            let mut delta = f64::INFINITY;
+            let mut j1 = 0;
+            for j in 1..=m {
+                if used[j] {
+                    continue;
+                }
+                let reduced = costs[i0 - 1][j - 1] - u[i0] - v[j];
+                if reduced < min_reduced[j] {
+                    min_reduced[j] = reduced;
+                    way[j] = j0;
+                }
+                if min_reduced[j] < delta {
+                    delta = min_reduced[j];
+                    j1 = j;
+                }
+            }
+            for j in 0..=m {
+                if used[j] {
+                    u[task[j]] += delta;
+                    v[j] -= delta;
+                } else {
+                    min_reduced[j] -= delta;
+                }
+            }
+            j0 = j1;
+            if task[j0] == 0 {
+                break;
+            }
+        }
+        // Flip the augmenting path.
+        while j0 != 0 {
+            let j1 = way[j0];
+            task[j0] = task[j1];
+            j0 = j1;
+        }
+    }
+
+    let mut assignment = vec![0; n];
+    for j in 1..=m {
+        if task[j] != 0 {
+            assignment[task[j] - 1] = j - 1;
+        }
+    }
+    assignment
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn total(costs: &[Vec<f64>], assignment: &[usize]) -> f64 {
+        assignment
+            .iter()
+            .enumerate()
+            .map(|(task, worker)| costs[task][*worker])
+            .sum()
+    }
+
+    #[test]
+    fn square() {
+        let costs = vec![
+            vec![4.0, 1.0, 3.0],
+            vec![2.0, 0.0, 5.0],
+            vec![3.0, 2.0, 2.0],
+        ];
+        let assignment = hungarian(&costs);
+        assert_eq!(assignment, vec![1, 0, 2]);
+        assert_eq!(total(&costs, &assignment), 5.0);
+    }
+
+    #[test]
+    fn rectangular() {
+        let costs = vec![vec![7.0, 3.0, 1.0, 9.0], vec![2.0, 8.0, 1.5, 6.0]];
+        let assignment = hungarian(&costs);
+        assert_eq!(assignment, vec![2, 0]);
+        assert_eq!(total(&costs, &assignment), 3.0);
+    }
+
+    #[test]
+    fn ties() {
+        let costs = vec![vec![1.0; 3]; 3];
+        let mut assignment = hungarian(&costs);
+        assert_eq!(total(&costs, &assignment), 3.0);
+        assignment.sort();
+        assert_eq!(assignment, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn empty() {
+        assert!(hungarian(&[]).is_empty());
+    }
+}
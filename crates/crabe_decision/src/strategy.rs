@@ -1,7 +1,18 @@
 use crate::action::ActionWrapper;
+use crate::role::Role;
 use crabe_framework::data::tool::ToolData;
 use crabe_framework::data::world::World;
 
+/// The `attack` module contains the strategies bringing the ball to the enemy goal.
+pub mod attack;
+/// The `basic` module contains simple strategies shared by the play books, such as stopping
+/// the robots.
+pub mod basic;
+/// The `defense` module contains the strategies protecting the ally goal.
+pub mod defense;
+/// The `set_piece` module contains the strategies restarting the game, such as kickoffs,
+/// free kicks and penalty kicks.
+pub mod set_piece;
 /// The `testing` module contains different strategies used for testing purposes. These strategies
 /// are not meant to be used in an actual game but rather to test specific functionalities or to
 /// experiment with different behaviors.
@@ -15,6 +26,16 @@ pub trait Strategy {
     /// Name of the strategy, that we use as simple reference
     fn name(&self) -> &'static str;
 
+    /// Roles the strategy needs robots for, at each step. A strategy controlling
+    /// fixed robots needs no role.
+    fn roles(&self, _world: &World) -> Vec<Role> {
+        vec![]
+    }
+
+    /// Gives the strategy the id of the robot assigned to each of its roles, or `None` when
+    /// there are not enough robots on the field for the role.
+    fn set_ids(&mut self, _ids: Vec<Option<u8>>) {}
+
     /// Executes one step of the strategy, updating the state of the robot and issuing commands
     /// to it through the given `ActionWrapper`.
     ///
@@ -0,0 +1,4 @@
+/// The `attacker` module contains the strategy of the robot playing the ball towards the
+/// enemy goal.
+mod attacker;
+pub use self::attacker::Attacker;
@@ -0,0 +1,147 @@
+use crate::action::aim_and_kick::AimAndKick;
+use crate::action::dribble::Dribble;
+use crate::action::go_to_ball::GoToBall;
+use crate::action::move_to::MoveTo;
+use crate::action::pass::Pass;
+use crate::action::receive::Receive;
+use crate::action::ActionWrapper;
+use crate::pass::{
+    best_target, expected_kick_time, open_goal_target, shot_opening, PassContract, SharedContract,
+    MAX_PASS_DISTANCE,
+};
+use crate::role::Role;
+use crate::strategy::Strategy;
+use crabe_framework::data::tool::ToolData;
+use crabe_framework::data::world::World;
+use nalgebra::Point2;
+
+/// The part of the enemy goal open from the ball above which the attacker shoots rather
+/// than passes.
+const MIN_SHOT_OPENING: f64 = 0.3;
+/// The score of a pass above which the attacker passes rather than shoots.
+const MIN_PASS_SCORE: f64 = 0.2;
+
+/// The `Attacker` struct represents a strategy bringing the ball into the enemy goal.
+///
+/// The attacker aims at the widest section of the goal not covered by the enemy robots,
+/// approaches the ball from behind, captures it with the dribbler, then turns with the ball
+/// and shoots. When the goal is covered, it passes the ball to the supporter, which waits
+/// for the pass at the best receiving point of the field.
+#[derive(Default)]
+pub struct Attacker {
+    /// The id of the attacker, if it is on the field.
+    id: Option<u8>,
+    /// The id of the supporter, if it is on the field.
+    support: Option<u8>,
+    /// The pass being played between the attacker and the supporter.
+    contract: Option<SharedContract>,
+}
+
+impl Attacker {
+    /// Creates a new Attacker instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Plans a pass from the attacker to the supporter, if the enemy goal is covered and a
+    /// good enough receiving point exists.
+    fn plan_pass(&self, world: &World, id: u8, ball: &Point2<f64>) -> Option<PassContract> {
+        let support = self.support?;
+        let robot = world.allies_bot.get(&id)?;
+        world.allies_bot.get(&support)?;
+        if shot_opening(world, ball) >= MIN_SHOT_OPENING {
+            return None;
+        }
+
+        let (target, score) = best_target(world, ball, MAX_PASS_DISTANCE)?;
+        if score < MIN_PASS_SCORE {
+            return None;
+        }
+        let kick_time = expected_kick_time(&robot.pose.position, ball);
+        Some(PassContract::new(id, support, *ball, target, kick_time))
+    }
+}
+
+impl Strategy for Attacker {
+    fn name(&self) -> &'static str {
+        "Attacker"
+    }
+
+    fn roles(&self, _world: &World) -> Vec<Role> {
+        vec![Role::Attacker, Role::Support]
+    }
+
+    fn set_ids(&mut self, ids: Vec<Option<u8>>) {
+        let id = ids.first().copied().flatten();
+        let support = ids.get(1).copied().flatten();
+        if (id, support) != (self.id, self.support) {
+            self.contract = None;
+        }
+        self.id = id;
+        self.support = support;
+    }
+
+    /// Starts a new attack each time the previous one ended, because the ball was kicked or
+    /// lost, and places the supporter while no pass is planned. The strategy never ends.
+    fn step(
+        &mut self,
+        world: &World,
+        _tools_data: &mut ToolData,
+        action_wrapper: &mut ActionWrapper,
+    ) -> bool {
+        let (Some(id), Some(ball)) = (self.id, &world.ball) else {
+            return false;
+        };
+        if !world.allies_bot.contains_key(&id) {
+            return false;
+        }
+        let ball = ball.position_2d();
+
+        // Wait while the supporter receives the pass, the attacker re-planning otherwise.
+        let receiving = self.contract.as_ref().is_some_and(|contract| {
+            contract.borrow().kicked
+                && self
+                    .support
+                    .is_some_and(|support| !action_wrapper.is_idle(support))
+        });
+        if action_wrapper.is_idle(id) && !receiving {
+            // A new attack ends the pass planned by the previous one, even if it was not
+            // played, so that the supporter does not wait for it forever.
+            if self.contract.take().is_some() {
+                if let Some(support) = self.support {
+                    action_wrapper.clear(support);
+                }
+            }
+            action_wrapper.clear(id);
+            if let Some(contract) = self.plan_pass(world, id, &ball) {
+                let contract = contract.shared();
+                action_wrapper.push(id, GoToBall::new(contract.borrow().target));
+                action_wrapper.push(id, Dribble::default());
+                action_wrapper.push(id, Pass::new(contract.clone()));
+                if let Some(support) = self.support {
+                    action_wrapper.clear(support);
+                    action_wrapper.push(support, Receive::new(contract.clone()));
+                }
+                self.contract = Some(contract);
+            } else {
+                let target = open_goal_target(world, &ball);
+                action_wrapper.push(id, GoToBall::new(target));
+                action_wrapper.push(id, Dribble::default());
+                action_wrapper.push(id, AimAndKick::new(target));
+            }
+        }
+
+        if self.contract.is_none() {
+            if let Some(support) = self.support {
+                if let Some((target, _)) = best_target(world, &ball, MAX_PASS_DISTANCE) {
+                    let facing = ball - target;
+                    action_wrapper.move_to(
+                        support,
+                        MoveTo::new(target, facing.y.atan2(facing.x), 0.0, false, None),
+                    );
+                }
+            }
+        }
+        false
+    }
+}
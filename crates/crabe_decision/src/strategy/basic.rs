@@ -0,0 +1,3 @@
+/// The `halt` module contains a strategy that stops every ally robot.
+mod halt;
+pub use self::halt::Halt;
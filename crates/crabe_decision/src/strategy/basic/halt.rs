@@ -0,0 +1,38 @@
+use crate::action::order_raw::RawOrder;
+use crate::action::ActionWrapper;
+use crate::strategy::Strategy;
+use crabe_framework::data::output::Command;
+use crabe_framework::data::tool::ToolData;
+use crabe_framework::data::world::World;
+
+/// The Halt struct represents a strategy that stops every ally robot on the field.
+/// It runs until the manager replaces it.
+#[derive(Default)]
+pub struct Halt;
+
+impl Halt {
+    /// Creates a new Halt instance.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Strategy for Halt {
+    fn name(&self) -> &'static str {
+        "Halt"
+    }
+
+    /// Replaces the actions of every ally robot by an order to stop.
+    fn step(
+        &mut self,
+        world: &World,
+        _tools_data: &mut ToolData,
+        action_wrapper: &mut ActionWrapper,
+    ) -> bool {
+        for id in world.allies_bot.keys() {
+            action_wrapper.clear(*id);
+            action_wrapper.push(*id, RawOrder::new(Command::default()));
+        }
+        false
+    }
+}
@@ -0,0 +1,80 @@
+/// The `goalkeeper` module contains the strategy of the robot defending the ally goal.
+mod goalkeeper;
+pub use self::goalkeeper::Goalkeeper;
+/// The `man_marking` module contains the strategy following the most dangerous enemy robots.
+mod man_marking;
+pub use self::man_marking::ManMarking;
+/// The `wall` module contains the strategy placing robots between the ball and the ally goal.
+mod wall;
+pub use self::wall::Wall;
+
+use crabe_framework::constant::STOP_BALL_DISTANCE;
+use crabe_framework::data::geometry::Area;
+use crabe_framework::data::world::{GameState, World};
+use crabe_navigation::constant::{OBSTACLE_MARGIN, ROBOT_RADIUS};
+use nalgebra::Point2;
+
+/// The speed in meters per second above which the ball is considered shot.
+const SHOT_SPEED: f64 = 0.5;
+/// The distance in meters beside the posts where a shot is still intercepted.
+const POST_MARGIN: f64 = 0.1;
+
+/// Returns the closest point to `point` where a field player is allowed to stand: outside of
+/// the defense areas and, when the ball is not in play, away from the ball.
+pub(crate) fn allowed_position(world: &World, point: Point2<f64>) -> Point2<f64> {
+    let mut point = point;
+    let geometry = &world.geometry;
+    for penalty in [&geometry.ally_penalty, &geometry.enemy_penalty] {
+        let area = Area::from_penalty(penalty, ROBOT_RADIUS + OBSTACLE_MARGIN);
+        if area.contains(&point) {
+            // Leave through the side facing the field, the other one being the goal line.
+            let front = if penalty.top_left_position.x > 0.0 {
+                area.min.x
+            } else {
+                area.max.x
+            };
+            let exits = [
+                Point2::new(front, point.y),
+                Point2::new(point.x, area.min.y),
+                Point2::new(point.x, area.max.y),
+            ];
+            point = exits
+                .into_iter()
+                .min_by(|a, b| (a - point).norm().total_cmp(&(b - point).norm()))
+                .unwrap_or(point);
+        }
+    }
+
+    let in_play = matches!(world.data.state, GameState::Running | GameState::ForceStart);
+    if let (false, Some(ball)) = (in_play, &world.ball) {
+        let ball = ball.position_2d();
+        let distance = STOP_BALL_DISTANCE + ROBOT_RADIUS + OBSTACLE_MARGIN;
+        let away = point - ball;
+        if away.norm() < distance {
+            point = ball
+                + away.try_normalize(f64::EPSILON).unwrap_or_else(|| {
+                    (geometry.ally_goal.center() - ball)
+                        .try_normalize(f64::EPSILON)
+                        .unwrap_or_default()
+                }) * distance;
+        }
+    }
+    point
+}
+
+/// Returns the point of the ally goal line where the ball would enter the goal, if the ball
+/// moves towards it.
+pub(crate) fn shot_target(world: &World) -> Option<Point2<f64>> {
+    let ball = world.ball.as_ref()?;
+    let goal = &world.geometry.ally_goal;
+    let center = goal.center();
+    let position = ball.position_2d();
+    let velocity = ball.velocity.xy();
+    if velocity.norm() < SHOT_SPEED || velocity.x * (center.x - position.x) <= 0.0 {
+        return None;
+    }
+
+    let time = (center.x - position.x) / velocity.x;
+    let target = position + velocity * time;
+    ((target.y - center.y).abs() < goal.width / 2.0 + POST_MARGIN).then_some(target)
+}
@@ -0,0 +1,159 @@
+use crate::action::move_to::{angle_wrap, MoveTo};
+use crate::action::ActionWrapper;
+use crate::role::Role;
+use crate::strategy::defense::shot_target;
+use crate::strategy::Strategy;
+use crabe_framework::data::geometry::Area;
+use crabe_framework::data::output::Kick;
+use crabe_framework::data::tool::ToolData;
+use crabe_framework::data::world::World;
+use crabe_navigation::constant::{BALL_RADIUS, ROBOT_RADIUS};
+use crabe_navigation::environment::Avoidance;
+use nalgebra::{Point2, Vector2};
+
+/// The distance in meters between the goal line and the keeper guarding the goal.
+const GUARD_DISTANCE: f64 = 0.25;
+/// The speed in meters per second below which the ball is considered stopped.
+const STOPPED_SPEED: f64 = 0.1;
+/// The power of the chip kick clearing the ball.
+const CLEAR_POWER: f32 = 3.0;
+/// The distance in meters between the front of the keeper and the ball before clearing it.
+const APPROACH_MARGIN: f64 = 0.05;
+/// The distance in meters to the line of the clearance below which the keeper is behind the
+/// ball.
+const ALIGN_TOLERANCE: f64 = 0.03;
+/// The angle in radians to the direction of the clearance below which the keeper faces it.
+const ALIGN_ANGLE: f64 = 0.1;
+
+/// The `Goalkeeper` struct represents a strategy defending the ally goal with the robot
+/// designated as goalkeeper to the referee.
+///
+/// The keeper stands on the bisector of the angle between the ball and the goal posts, moves
+/// on the trajectory of the ball when it is shot towards the goal, and clears the ball with
+/// a chip kick when it stops in the penalty area, after going behind it. It always stays
+/// inside the penalty area.
+#[derive(Default)]
+pub struct Goalkeeper {
+    /// The id of the keeper, if it is on the field.
+    id: Option<u8>,
+}
+
+impl Goalkeeper {
+    /// Creates a new Goalkeeper instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the position on the bisector of the angle between the ball and the goal posts,
+    /// at the guard distance from the goal line.
+    fn guard_position(world: &World, ball: Point2<f64>) -> Point2<f64> {
+        let goal = &world.geometry.ally_goal;
+        let center = goal.center();
+        let half_width = Vector2::new(0.0, goal.width / 2.0);
+        let (left, right) = (center + half_width, center - half_width);
+        let unit = |vector: Vector2<f64>| vector.try_normalize(f64::EPSILON).unwrap_or_default();
+        let bisector = (unit(left - ball) + unit(right - ball)).try_normalize(f64::EPSILON);
+
+        // The point of the goal line the bisector goes through.
+        let aimed = match bisector {
+            Some(bisector) if bisector.x.abs() > f64::EPSILON => {
+                let y = ball.y + bisector.y * (center.x - ball.x) / bisector.x;
+                Point2::new(center.x, y.clamp(right.y.min(left.y), right.y.max(left.y)))
+            }
+            _ => center,
+        };
+        let forward = Vector2::new(-center.x.signum(), 0.0);
+        aimed
+            + (ball - aimed)
+                .try_normalize(f64::EPSILON)
+                .unwrap_or(forward)
+                * GUARD_DISTANCE
+    }
+}
+
+impl Strategy for Goalkeeper {
+    fn name(&self) -> &'static str {
+        "Goalkeeper"
+    }
+
+    fn roles(&self, _world: &World) -> Vec<Role> {
+        vec![Role::Goalkeeper]
+    }
+
+    fn set_ids(&mut self, ids: Vec<Option<u8>>) {
+        self.id = ids.first().copied().flatten();
+    }
+
+    /// Moves the keeper to intercept the ball, or to clear it when it stops in the penalty
+    /// area. The strategy never ends.
+    fn step(
+        &mut self,
+        world: &World,
+        _tools_data: &mut ToolData,
+        action_wrapper: &mut ActionWrapper,
+    ) -> bool {
+        let Some(robot) = self.id.and_then(|id| world.allies_bot.get(&id)) else {
+            return false;
+        };
+        let area = Area::from_penalty(&world.geometry.ally_penalty, -ROBOT_RADIUS);
+        let position = robot.pose.position;
+        let ball = world
+            .ball
+            .as_ref()
+            .map_or(world.geometry.center.center, |ball| ball.position_2d());
+        let goal = world.geometry.ally_goal.center();
+        let stopped = world
+            .ball
+            .as_ref()
+            .is_some_and(|ball| ball.velocity.xy().norm() < STOPPED_SPEED);
+
+        if stopped && area.contains(&ball) {
+            // Push the ball away from the goal, facing the field.
+            let direction = (ball - goal)
+                .try_normalize(f64::EPSILON)
+                .unwrap_or(Vector2::new(-goal.x.signum(), 0.0));
+            let orientation = direction.y.atan2(direction.x);
+            let to_ball = ball - position;
+            let behind = (0.0..=ROBOT_RADIUS + BALL_RADIUS + APPROACH_MARGIN + ALIGN_TOLERANCE)
+                .contains(&to_ball.dot(&direction))
+                && to_ball.perp(&direction).abs() < ALIGN_TOLERANCE
+                && angle_wrap(orientation - robot.pose.orientation).abs() < ALIGN_ANGLE;
+            let move_to = if behind {
+                MoveTo::new(
+                    ball,
+                    orientation,
+                    0.0,
+                    true,
+                    Some(Kick::ChipKick { power: CLEAR_POWER }),
+                )
+            } else {
+                // Go around the ball first, so as not to push it towards the goal.
+                let approach = ball - direction * (ROBOT_RADIUS + BALL_RADIUS + APPROACH_MARGIN);
+                MoveTo::new(approach, orientation, 0.0, true, None).with_avoidance(Avoidance {
+                    touch_ball: false,
+                    ..Default::default()
+                })
+            };
+            action_wrapper.move_to(robot.id, move_to);
+            return false;
+        }
+
+        let target = match shot_target(world) {
+            Some(target) => {
+                // The closest point of the trajectory of the ball.
+                let trajectory = target - ball;
+                let t = ((position - ball).dot(&trajectory) / trajectory.norm_squared())
+                    .clamp(0.0, 1.0);
+                ball + trajectory * t
+            }
+            None => Self::guard_position(world, ball),
+        };
+        let target = target.sup(&area.min).inf(&area.max);
+        let facing = ball - target;
+        action_wrapper.move_to(
+            robot.id,
+            MoveTo::new(target, facing.y.atan2(facing.x), 0.0, false, None),
+        );
+        false
+    }
+}
@@ -0,0 +1,153 @@
+use crate::action::move_to::MoveTo;
+use crate::action::ActionWrapper;
+use crate::role::{hungarian, Role};
+use crate::strategy::defense::allowed_position;
+use crate::strategy::Strategy;
+use crabe_framework::data::geometry::Area;
+use crabe_framework::data::tool::ToolData;
+use crabe_framework::data::world::{EnemyInfo, Robot, World};
+use crabe_navigation::constant::{BALL_RADIUS, ROBOT_RADIUS};
+use crabe_navigation::environment::{distance_to_segment, Avoidance};
+use nalgebra::{Point2, Vector2};
+use std::f64::consts::PI;
+
+/// The distance in meters between a marked enemy robot and its marker.
+const MARKING_DISTANCE: f64 = 0.3;
+/// The distance in meters to the ball below which an enemy robot holds the ball and is left
+/// to the other defenders.
+const BALL_HOLDER_DISTANCE: f64 = 0.3;
+/// The weight of the closeness to the ally goal in the threat of an enemy robot.
+const GOAL_WEIGHT: f64 = 0.4;
+/// The weight of the open shot angle in the threat of an enemy robot.
+const SHOT_WEIGHT: f64 = 0.3;
+/// The weight of the possibility to receive a pass in the threat of an enemy robot.
+const PASS_WEIGHT: f64 = 0.3;
+/// The length in meters of the pass beyond which the pass is considered unlikely.
+const PASS_RANGE: f64 = 6.0;
+
+/// Returns the threat, between 0 and 1, of an enemy robot which could receive the ball.
+///
+/// The threat combines the closeness of the enemy robot to the ally goal, the angle under
+/// which it sees the ally goal, and whether the ball can be passed to it without hitting an
+/// ally robot.
+fn threat(world: &World, enemy: &Robot<EnemyInfo>, ball: &Point2<f64>) -> f64 {
+    let geometry = &world.geometry;
+    let goal = geometry.ally_goal.center();
+    let position = enemy.pose.position;
+    let closeness = (1.0 - (goal - position).norm() / geometry.field.length).max(0.0);
+
+    let half_width = Vector2::new(0.0, geometry.ally_goal.width / 2.0);
+    let (left, right) = (goal + half_width - position, goal - half_width - position);
+    let shot_angle = left.angle(&right) / PI;
+
+    let pass_open = world.allies_bot.values().all(|robot| {
+        distance_to_segment(&robot.pose.position, ball, &position) > ROBOT_RADIUS + BALL_RADIUS
+    });
+    let reachability = if pass_open {
+        (1.0 - (position - ball).norm() / PASS_RANGE).max(0.0)
+    } else {
+        0.0
+    };
+
+    GOAL_WEIGHT * closeness + SHOT_WEIGHT * shot_angle + PASS_WEIGHT * reachability
+}
+
+/// The `ManMarking` struct represents a strategy following the most dangerous enemy robots
+/// to prevent them from receiving the ball.
+///
+/// The enemy robots are ranked by threat, ignoring the one holding the ball and the enemy
+/// goalkeeper, and each marker stands between its enemy robot and the ball. The markers are
+/// given to the enemy robots minimizing the distance they travel.
+pub struct ManMarking {
+    /// The maximum number of robots marking an enemy robot.
+    size: usize,
+    /// The ids of the markers.
+    ids: Vec<u8>,
+}
+
+impl ManMarking {
+    /// Creates a new ManMarking instance marking at most `size` enemy robots.
+    pub fn new(size: usize) -> Self {
+        Self { size, ids: vec![] }
+    }
+}
+
+impl Strategy for ManMarking {
+    fn name(&self) -> &'static str {
+        "ManMarking"
+    }
+
+    fn roles(&self, _world: &World) -> Vec<Role> {
+        vec![Role::Defender; self.size]
+    }
+
+    fn set_ids(&mut self, ids: Vec<Option<u8>>) {
+        self.ids = ids.into_iter().flatten().collect();
+    }
+
+    /// Places each marker between the ball and the enemy robot it marks. The strategy never
+    /// ends.
+    fn step(
+        &mut self,
+        world: &World,
+        _tools_data: &mut ToolData,
+        action_wrapper: &mut ActionWrapper,
+    ) -> bool {
+        let markers: Vec<_> = self
+            .ids
+            .iter()
+            .filter_map(|id| world.allies_bot.get(id))
+            .collect();
+        let Some(ball) = &world.ball else {
+            return false;
+        };
+        let ball = ball.position_2d();
+        let enemy_area = Area::from_penalty(&world.geometry.enemy_penalty, 0.0);
+
+        let mut threats: Vec<(f64, Point2<f64>)> = world
+            .enemies_bot
+            .values()
+            .filter(|enemy| {
+                (enemy.pose.position - ball).norm() > BALL_HOLDER_DISTANCE
+                    && !enemy_area.contains(&enemy.pose.position)
+            })
+            .map(|enemy| (threat(world, enemy, &ball), enemy.pose.position))
+            .collect();
+        threats.sort_by(|(a, _), (b, _)| b.total_cmp(a));
+        threats.truncate(markers.len());
+
+        let positions: Vec<Point2<f64>> = threats
+            .iter()
+            .map(|(_, enemy)| {
+                let to_ball = (ball - enemy)
+                    .try_normalize(f64::EPSILON)
+                    .unwrap_or_default();
+                allowed_position(world, enemy + to_ball * MARKING_DISTANCE)
+            })
+            .collect();
+        let costs: Vec<Vec<f64>> = positions
+            .iter()
+            .map(|position| {
+                markers
+                    .iter()
+                    .map(|marker| (marker.pose.position - position).norm())
+                    .collect()
+            })
+            .collect();
+
+        for (position, column) in positions.iter().zip(hungarian(&costs)) {
+            let id = markers[column].id;
+            let facing = ball - position;
+            action_wrapper.move_to(
+                id,
+                MoveTo::new(*position, facing.y.atan2(facing.x), 0.0, false, None).with_avoidance(
+                    Avoidance {
+                        avoid_penalty_areas: true,
+                        ..Default::default()
+                    },
+                ),
+            );
+        }
+        false
+    }
+}
@@ -0,0 +1,117 @@
+use crate::action::move_to::{angle_wrap, MoveTo};
+use crate::action::ActionWrapper;
+use crate::role::Role;
+use crate::strategy::defense::allowed_position;
+use crate::strategy::Strategy;
+use crabe_framework::data::tool::ToolData;
+use crabe_framework::data::world::World;
+use crabe_navigation::constant::{OBSTACLE_MARGIN, ROBOT_RADIUS};
+use crabe_navigation::environment::Avoidance;
+use nalgebra::{Point2, Vector2};
+use std::f64::consts::{FRAC_PI_2, PI};
+
+/// The space in meters left between two robots of the wall.
+const WALL_GAP: f64 = 0.02;
+
+/// The `Wall` struct represents a strategy placing robots side by side on an arc around the
+/// ally penalty area, centered on the line between the ball and the ally goal, to block the
+/// shots.
+///
+/// The wall is made of the robots assigned to it, so it shrinks when robots are missing.
+pub struct Wall {
+    /// The maximum number of robots in the wall.
+    size: usize,
+    /// The ids of the robots of the wall.
+    ids: Vec<u8>,
+}
+
+impl Wall {
+    /// Creates a new Wall instance made of at most `size` robots.
+    pub fn new(size: usize) -> Self {
+        Self { size, ids: vec![] }
+    }
+
+    /// Returns the positions of the robots of the wall, blocking the ball.
+    fn positions(world: &World, ball: Point2<f64>, count: usize) -> Vec<Point2<f64>> {
+        let geometry = &world.geometry;
+        let goal = geometry.ally_goal.center();
+        let penalty = &geometry.ally_penalty;
+        let radius = penalty.depth.hypot(penalty.width / 2.0) + ROBOT_RADIUS + OBSTACLE_MARGIN;
+
+        // Stay in front of the goal line even when the ball is behind it.
+        let forward = if goal.x > 0.0 { PI } else { 0.0 };
+        let to_ball = ball - goal;
+        let offset = angle_wrap(to_ball.y.atan2(to_ball.x) - forward).clamp(-FRAC_PI_2, FRAC_PI_2);
+        let spacing = (2.0 * ROBOT_RADIUS + WALL_GAP) / radius;
+        (0..count)
+            .map(|i| {
+                let angle = forward + offset + spacing * (i as f64 - (count - 1) as f64 / 2.0);
+                goal + Vector2::new(angle.cos(), angle.sin()) * radius
+            })
+            .collect()
+    }
+}
+
+impl Strategy for Wall {
+    fn name(&self) -> &'static str {
+        "Wall"
+    }
+
+    fn roles(&self, _world: &World) -> Vec<Role> {
+        vec![Role::Defender; self.size]
+    }
+
+    fn set_ids(&mut self, ids: Vec<Option<u8>>) {
+        let mut ids: Vec<u8> = ids.into_iter().flatten().collect();
+        ids.sort();
+        self.ids = ids;
+    }
+
+    /// Places the robots of the wall between the ball and the ally goal. The strategy never
+    /// ends.
+    fn step(
+        &mut self,
+        world: &World,
+        _tools_data: &mut ToolData,
+        action_wrapper: &mut ActionWrapper,
+    ) -> bool {
+        let mut robots: Vec<_> = self
+            .ids
+            .iter()
+            .filter_map(|id| world.allies_bot.get(id))
+            .collect();
+        if robots.is_empty() {
+            return false;
+        }
+        let ball = world
+            .ball
+            .as_ref()
+            .map_or(world.geometry.center.center, |ball| ball.position_2d());
+
+        // Order the robots along the wall as they are on the field, so that they do not
+        // cross each other.
+        let mut positions = Self::positions(world, ball, robots.len());
+        let goal = world.geometry.ally_goal.center();
+        let angle = |point: &Point2<f64>| {
+            let relative = point - goal;
+            (relative.y * goal.x.signum()).atan2(-relative.x * goal.x.signum())
+        };
+        robots.sort_by(|a, b| angle(&a.pose.position).total_cmp(&angle(&b.pose.position)));
+        positions.sort_by(|a, b| angle(a).total_cmp(&angle(b)));
+
+        for (robot, position) in robots.iter().zip(positions) {
+            let target = allowed_position(world, position);
+            let facing = ball - target;
+            action_wrapper.move_to(
+                robot.id,
+                MoveTo::new(target, facing.y.atan2(facing.x), 0.0, false, None).with_avoidance(
+                    Avoidance {
+                        avoid_penalty_areas: true,
+                        ..Default::default()
+                    },
+                ),
+            );
+        }
+        false
+    }
+}
@@ -0,0 +1,16 @@
+/// The `kick_taker` module contains the strategy of the robot restarting the game by kicking
+/// the ball, with a shot or a short pass.
+mod kick_taker;
+pub use self::kick_taker::KickTaker;
+/// The `kickoff` module contains the strategy placing the robots in the ally half for a
+/// kickoff.
+mod kickoff;
+pub use self::kickoff::KickoffFormation;
+/// The `penalty` module contains the strategies of the shooter, of the goalkeeper and of the
+/// other robots during a penalty kick.
+mod penalty;
+pub use self::penalty::{PenaltyFormation, PenaltyKeeper, PenaltyShooter};
+/// The `placement` module contains the strategies placing the ball for an ally ball
+/// placement and keeping the robots away from an enemy ball placement.
+mod placement;
+pub use self::placement::{BallPlacer, KeepOutOfPlacement};
@@ -0,0 +1,134 @@
+use crate::action::aim_and_kick::AimAndKick;
+use crate::action::dribble::Dribble;
+use crate::action::go_to_ball::GoToBall;
+use crate::action::pass::Pass;
+use crate::action::receive::Receive;
+use crate::action::ActionWrapper;
+use crate::pass::{
+    best_ally_half_target, best_target, expected_kick_time, open_goal_target, shot_opening,
+    PassContract,
+};
+use crate::role::Role;
+use crate::strategy::Strategy;
+use crabe_framework::data::tool::ToolData;
+use crabe_framework::data::world::{GameState, Restart, World};
+use nalgebra::Point2;
+
+/// The part of the enemy goal open from the ball above which the kick taker shoots.
+const MIN_SHOT_OPENING: f64 = 0.3;
+/// The distance in meters to the enemy goal below which the kick taker may shoot.
+const MAX_SHOT_DISTANCE: f64 = 4.0;
+/// The longest pass in meters played by the kick taker.
+const SHORT_PASS_DISTANCE: f64 = 2.5;
+
+/// The `KickTaker` struct represents a strategy restarting the game with a kick, for a
+/// kickoff or a free kick.
+///
+/// The kick taker shoots when the enemy goal is close and open, otherwise it plays a short
+/// pass to the supporter, and shoots if no pass is possible. The strategy ends once the ball
+/// was kicked, so that the kick taker does not touch the ball twice, and plans the kick again
+/// when it fails before.
+#[derive(Default)]
+pub struct KickTaker {
+    /// The id of the kick taker, if it is on the field.
+    id: Option<u8>,
+    /// The id of the supporter, if it is on the field.
+    support: Option<u8>,
+    /// Whether the kick was planned.
+    started: bool,
+}
+
+impl KickTaker {
+    /// Creates a new KickTaker instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the pass to play to the supporter, if the ball should not be shot. For a
+    /// kickoff, the supporter waits for the ball in the ally half.
+    fn plan_pass(&self, world: &World, id: u8, ball: &Point2<f64>) -> Option<PassContract> {
+        let support = self
+            .support
+            .filter(|id| world.allies_bot.contains_key(id))?;
+        let to_goal = (world.geometry.enemy_goal.center() - ball).norm();
+        if to_goal < MAX_SHOT_DISTANCE && shot_opening(world, ball) >= MIN_SHOT_OPENING {
+            return None;
+        }
+        let kickoff = matches!(
+            world.data.state,
+            GameState::NormalStart(Restart::Kickoff(_))
+        );
+        let (target, _) = if kickoff {
+            best_ally_half_target(world, ball, SHORT_PASS_DISTANCE)?
+        } else {
+            best_target(world, ball, SHORT_PASS_DISTANCE)?
+        };
+        let position = world.allies_bot.get(&id)?.pose.position;
+        let kick_time = expected_kick_time(&position, ball);
+        Some(PassContract::new(id, support, *ball, target, kick_time))
+    }
+}
+
+impl Strategy for KickTaker {
+    fn name(&self) -> &'static str {
+        "KickTaker"
+    }
+
+    fn roles(&self, _world: &World) -> Vec<Role> {
+        vec![Role::Attacker, Role::Support]
+    }
+
+    fn set_ids(&mut self, ids: Vec<Option<u8>>) {
+        let id = ids.first().copied().flatten();
+        if id != self.id {
+            self.started = false;
+        }
+        self.id = id;
+        self.support = ids.get(1).copied().flatten();
+    }
+
+    /// Plans the kick, then waits for the kick taker to finish it, planning it again if it
+    /// fails.
+    fn step(
+        &mut self,
+        world: &World,
+        _tools_data: &mut ToolData,
+        action_wrapper: &mut ActionWrapper,
+    ) -> bool {
+        let (Some(id), Some(ball)) = (self.id, &world.ball) else {
+            return false;
+        };
+        if !world.allies_bot.contains_key(&id) {
+            return false;
+        }
+        if self.started {
+            // The actions are all done only once the ball was kicked. When one of them fails,
+            // for instance when the ball is not seen for a moment, the kick is planned again.
+            if !action_wrapper.has_failed(id) {
+                return action_wrapper.is_idle(id);
+            }
+            if let Some(support) = self.support {
+                action_wrapper.clear(support);
+            }
+        }
+
+        let ball = ball.position_2d();
+        action_wrapper.clear(id);
+        if let Some(contract) = self.plan_pass(world, id, &ball) {
+            let contract = contract.shared();
+            action_wrapper.push(id, GoToBall::new(contract.borrow().target));
+            action_wrapper.push(id, Dribble::default());
+            action_wrapper.push(id, Pass::new(contract.clone()));
+            let receiver = contract.borrow().receiver;
+            action_wrapper.clear(receiver);
+            action_wrapper.push(receiver, Receive::new(contract));
+        } else {
+            let target = open_goal_target(world, &ball);
+            action_wrapper.push(id, GoToBall::new(target));
+            action_wrapper.push(id, Dribble::default());
+            action_wrapper.push(id, AimAndKick::new(target));
+        }
+        self.started = true;
+        false
+    }
+}
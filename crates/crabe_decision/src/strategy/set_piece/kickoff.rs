@@ -0,0 +1,117 @@
+use crate::action::move_to::MoveTo;
+use crate::action::ActionWrapper;
+use crate::role::Role;
+use crate::strategy::defense::allowed_position;
+use crate::strategy::Strategy;
+use crabe_framework::data::tool::ToolData;
+use crabe_framework::data::world::World;
+use crabe_navigation::constant::{BALL_RADIUS, ROBOT_RADIUS};
+use crabe_navigation::environment::Avoidance;
+use nalgebra::Point2;
+
+/// The positions of the robots for a kickoff, as the distance in meters from the center line
+/// towards the ally goal and the coordinate along the center line. They are outside of the
+/// center circle.
+const FORMATION: [(f64, f64); 5] = [
+    (0.25, 1.2),
+    (0.25, -1.2),
+    (1.5, 0.6),
+    (1.5, -0.6),
+    (2.5, 0.0),
+];
+/// The distance in meters between the ball and the front of the kicker waiting for the
+/// kickoff.
+const KICKER_MARGIN: f64 = 0.1;
+
+/// The `KickoffFormation` struct represents a strategy placing the robots in the ally half
+/// for a kickoff, outside of the center circle. When preparing an ally kickoff, the first
+/// robot waits behind the ball to take it.
+///
+/// The strategy never ends, so that the robots stay in place until the game runs.
+pub struct KickoffFormation {
+    /// Whether a robot waits behind the ball to take the kickoff.
+    kicker: bool,
+    /// The ids of the robots, the kicker first.
+    ids: Vec<Option<u8>>,
+}
+
+impl KickoffFormation {
+    /// Creates a new KickoffFormation instance, with a robot waiting behind the ball if
+    /// `kicker` is set.
+    pub fn new(kicker: bool) -> Self {
+        Self {
+            kicker,
+            ids: vec![],
+        }
+    }
+}
+
+impl Strategy for KickoffFormation {
+    fn name(&self) -> &'static str {
+        "KickoffFormation"
+    }
+
+    fn roles(&self, _world: &World) -> Vec<Role> {
+        let kicker = self.kicker.then_some(Role::Attacker);
+        let formation = [
+            Role::Support,
+            Role::Support,
+            Role::Defender,
+            Role::Defender,
+            Role::Defender,
+        ];
+        kicker.into_iter().chain(formation).collect()
+    }
+
+    fn set_ids(&mut self, ids: Vec<Option<u8>>) {
+        self.ids = ids;
+    }
+
+    /// Moves the robots to their position of the formation.
+    fn step(
+        &mut self,
+        world: &World,
+        _tools_data: &mut ToolData,
+        action_wrapper: &mut ActionWrapper,
+    ) -> bool {
+        let geometry = &world.geometry;
+        let side = geometry.ally_goal.center().x.signum();
+        let ball = world
+            .ball
+            .as_ref()
+            .map_or(geometry.center.center, |ball| ball.position_2d());
+
+        let kicker = self.kicker.then(|| {
+            let distance = ROBOT_RADIUS + BALL_RADIUS + KICKER_MARGIN;
+            let position = Point2::new(ball.x + side * distance, ball.y);
+            let avoidance = Avoidance {
+                touch_ball: false,
+                ..Default::default()
+            };
+            (position, avoidance)
+        });
+        let formation = FORMATION.iter().map(|(depth, y)| {
+            let position = allowed_position(world, Point2::new(side * depth, *y));
+            let avoidance = Avoidance {
+                avoid_ball: true,
+                avoid_penalty_areas: true,
+                ..Default::default()
+            };
+            (position, avoidance)
+        });
+
+        for (id, (position, avoidance)) in self.ids.iter().zip(kicker.into_iter().chain(formation))
+        {
+            let Some(id) = id else {
+                continue;
+            };
+            let facing = ball - position;
+            action_wrapper.move_to(
+                *id,
+                MoveTo::new(position, facing.y.atan2(facing.x), 0.0, false, None)
+                    .with_avoidance(avoidance),
+            );
+        }
+        false
+    }
+}
@@ -0,0 +1,251 @@
+use crate::action::aim_and_kick::AimAndKick;
+use crate::action::dribble::Dribble;
+use crate::action::go_to_ball::GoToBall;
+use crate::action::move_to::MoveTo;
+use crate::action::ActionWrapper;
+use crate::pass::open_goal_target;
+use crate::role::Role;
+use crate::strategy::defense::shot_target;
+use crate::strategy::Strategy;
+use crabe_framework::constant::PENALTY_DISTANCE;
+use crabe_framework::data::tool::ToolData;
+use crabe_framework::data::world::World;
+use crabe_navigation::constant::{BALL_RADIUS, ROBOT_RADIUS};
+use crabe_navigation::environment::Avoidance;
+use nalgebra::Point2;
+
+/// The distance in meters between the robots waiting behind the ball.
+const FORMATION_SPACING: f64 = 0.4;
+/// The distance in meters between the ball and the front of the shooter waiting for the
+/// penalty kick.
+const SHOOTER_MARGIN: f64 = 0.1;
+
+/// The `PenaltyShooter` struct represents a strategy taking an ally penalty kick: the shooter
+/// waits behind the ball while the penalty is prepared, then shoots at the widest open
+/// section of the enemy goal once allowed.
+///
+/// When shooting, the strategy ends once the ball was kicked, and plans the shot again when
+/// it fails before.
+pub struct PenaltyShooter {
+    /// Whether the shooter may shoot, or only prepares.
+    shoot: bool,
+    /// The id of the shooter, if it is on the field.
+    id: Option<u8>,
+    /// Whether the shot was planned.
+    started: bool,
+}
+
+impl PenaltyShooter {
+    /// Creates a new PenaltyShooter instance, shooting if `shoot` is set and waiting behind
+    /// the ball otherwise.
+    pub fn new(shoot: bool) -> Self {
+        Self {
+            shoot,
+            id: None,
+            started: false,
+        }
+    }
+}
+
+impl Strategy for PenaltyShooter {
+    fn name(&self) -> &'static str {
+        "PenaltyShooter"
+    }
+
+    fn roles(&self, _world: &World) -> Vec<Role> {
+        vec![Role::Attacker]
+    }
+
+    fn set_ids(&mut self, ids: Vec<Option<u8>>) {
+        let id = ids.first().copied().flatten();
+        if id != self.id {
+            self.started = false;
+        }
+        self.id = id;
+    }
+
+    /// Places the shooter behind the ball, or plans the shot and waits for the kick, planning
+    /// it again if it fails.
+    fn step(
+        &mut self,
+        world: &World,
+        _tools_data: &mut ToolData,
+        action_wrapper: &mut ActionWrapper,
+    ) -> bool {
+        let (Some(id), Some(ball)) = (self.id, &world.ball) else {
+            return false;
+        };
+        if !world.allies_bot.contains_key(&id) {
+            return false;
+        }
+        if self.started && !action_wrapper.has_failed(id) {
+            // The actions are all done only once the ball was kicked.
+            return action_wrapper.is_idle(id);
+        }
+
+        let ball = ball.position_2d();
+        let target = open_goal_target(world, &ball);
+        if self.shoot {
+            action_wrapper.clear(id);
+            action_wrapper.push(id, GoToBall::new(target));
+            action_wrapper.push(id, Dribble::default());
+            action_wrapper.push(id, AimAndKick::new(target));
+            self.started = true;
+        } else {
+            let direction = (target - ball).normalize();
+            let position = ball - direction * (ROBOT_RADIUS + BALL_RADIUS + SHOOTER_MARGIN);
+            action_wrapper.move_to(
+                id,
+                MoveTo::new(position, direction.y.atan2(direction.x), 0.0, false, None)
+                    .with_avoidance(Avoidance {
+                        touch_ball: false,
+                        ..Default::default()
+                    }),
+            );
+        }
+        false
+    }
+}
+
+/// The `PenaltyKeeper` struct represents a strategy defending an enemy penalty kick: the
+/// goalkeeper stays on the goal line, in front of the ball, and moves along the line onto
+/// the trajectory of the ball once shot.
+#[derive(Default)]
+pub struct PenaltyKeeper {
+    /// The id of the goalkeeper, if it is on the field.
+    id: Option<u8>,
+}
+
+impl PenaltyKeeper {
+    /// Creates a new PenaltyKeeper instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Strategy for PenaltyKeeper {
+    fn name(&self) -> &'static str {
+        "PenaltyKeeper"
+    }
+
+    fn roles(&self, _world: &World) -> Vec<Role> {
+        vec![Role::Goalkeeper]
+    }
+
+    fn set_ids(&mut self, ids: Vec<Option<u8>>) {
+        self.id = ids.first().copied().flatten();
+    }
+
+    /// Moves the goalkeeper along the goal line. The strategy never ends.
+    fn step(
+        &mut self,
+        world: &World,
+        _tools_data: &mut ToolData,
+        action_wrapper: &mut ActionWrapper,
+    ) -> bool {
+        let Some(id) = self.id.filter(|id| world.allies_bot.contains_key(id)) else {
+            return false;
+        };
+        let goal = &world.geometry.ally_goal;
+        let center = goal.center();
+        let ball = world
+            .ball
+            .as_ref()
+            .map_or(world.geometry.center.center, |ball| ball.position_2d());
+
+        let y = shot_target(world).map_or(ball.y, |target| target.y);
+        let half_width = goal.width / 2.0 - ROBOT_RADIUS;
+        let target = Point2::new(
+            center.x - center.x.signum() * ROBOT_RADIUS,
+            y.clamp(center.y - half_width, center.y + half_width),
+        );
+        let facing = ball - target;
+        action_wrapper.move_to(
+            id,
+            MoveTo::new(target, facing.y.atan2(facing.x), 0.0, false, None),
+        );
+        false
+    }
+}
+
+/// The `PenaltyFormation` struct represents a strategy placing the robots other than the
+/// shooter and the goalkeeper on a line behind the ball during a penalty kick, as required
+/// by the rules.
+pub struct PenaltyFormation {
+    /// The maximum number of robots placed.
+    size: usize,
+    /// Whether the ally team takes the penalty kick.
+    attacking: bool,
+    /// The ids of the robots placed.
+    ids: Vec<u8>,
+}
+
+impl PenaltyFormation {
+    /// Creates a new PenaltyFormation instance placing at most `size` robots, for a penalty
+    /// kick taken by the ally team if `attacking` is set.
+    pub fn new(size: usize, attacking: bool) -> Self {
+        Self {
+            size,
+            attacking,
+            ids: vec![],
+        }
+    }
+}
+
+impl Strategy for PenaltyFormation {
+    fn name(&self) -> &'static str {
+        "PenaltyFormation"
+    }
+
+    fn roles(&self, _world: &World) -> Vec<Role> {
+        vec![Role::Support; self.size]
+    }
+
+    fn set_ids(&mut self, ids: Vec<Option<u8>>) {
+        self.ids = ids.into_iter().flatten().collect();
+    }
+
+    /// Moves the robots to the line behind the ball. The strategy never ends.
+    fn step(
+        &mut self,
+        world: &World,
+        _tools_data: &mut ToolData,
+        action_wrapper: &mut ActionWrapper,
+    ) -> bool {
+        let Some(ball) = &world.ball else {
+            return false;
+        };
+        let ball = ball.position_2d();
+        let geometry = &world.geometry;
+        let goal = if self.attacking {
+            geometry.enemy_goal.center()
+        } else {
+            geometry.ally_goal.center()
+        };
+        let x = ball.x - goal.x.signum() * (PENALTY_DISTANCE + ROBOT_RADIUS);
+
+        let robots: Vec<u8> = self
+            .ids
+            .iter()
+            .copied()
+            .filter(|id| world.allies_bot.contains_key(id))
+            .collect();
+        let count = robots.len();
+        for (index, id) in robots.into_iter().enumerate() {
+            let offset = (index as f64 - (count - 1) as f64 / 2.0) * FORMATION_SPACING;
+            let target = Point2::new(x, ball.y + offset);
+            let facing = ball - target;
+            action_wrapper.move_to(
+                id,
+                MoveTo::new(target, facing.y.atan2(facing.x), 0.0, false, None).with_avoidance(
+                    Avoidance {
+                        avoid_ball: true,
+                        avoid_penalty_areas: true,
+                        ..Default::default()
+                    },
+                ),
+            );
+        }
+        false
+    }
+}
@@ -0,0 +1,220 @@
+use crate::action::dribble::Dribble;
+use crate::action::dribble_to::{DribbleTo, PLACEMENT_TOLERANCE};
+use crate::action::go_to_ball::GoToBall;
+use crate::action::move_to::MoveTo;
+use crate::action::order_raw::RawOrder;
+use crate::action::pass::Pass;
+use crate::action::receive::Receive;
+use crate::action::ActionWrapper;
+use crate::pass::{expected_kick_time, PassContract};
+use crate::role::Role;
+use crate::strategy::Strategy;
+use crabe_framework::constant::PLACEMENT_DISTANCE;
+use crabe_framework::data::output::Command;
+use crabe_framework::data::tool::ToolData;
+use crabe_framework::data::world::World;
+use crabe_navigation::constant::{BALL_RADIUS, OBSTACLE_MARGIN, ROBOT_RADIUS};
+use crabe_navigation::environment::Avoidance;
+use nalgebra::{Point2, Vector2};
+
+/// The distance in meters between the ball and its placement position above which the ball
+/// is passed to the supporter rather than dribbled.
+const LONG_PLACEMENT_DISTANCE: f64 = 3.0;
+/// The distance in meters between the front of the robot and the placed ball once the robot
+/// left it.
+const LEAVE_DISTANCE: f64 = 0.5;
+
+/// Returns the order moving the robot out of the corridor between the ball and its placement
+/// position, or keeping it in place when it is already out.
+fn keep_out(world: &World, id: u8, ball: Point2<f64>, designated: Point2<f64>) -> Option<MoveTo> {
+    let robot = world.allies_bot.get(&id)?;
+    let position = robot.pose.position;
+    let segment = designated - ball;
+    let length_squared = segment.norm_squared();
+    let t = if length_squared > 0.0 {
+        ((position - ball).dot(&segment) / length_squared).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let closest = ball + segment * t;
+    let radius = PLACEMENT_DISTANCE + ROBOT_RADIUS + 2.0 * OBSTACLE_MARGIN;
+
+    let away = position - closest;
+    let target = if away.norm() < radius {
+        let direction = away
+            .try_normalize(f64::EPSILON)
+            .or_else(|| Vector2::new(-segment.y, segment.x).try_normalize(f64::EPSILON))
+            .unwrap_or_else(Vector2::x);
+        closest + direction * radius
+    } else {
+        position
+    };
+    Some(
+        MoveTo::new(target, robot.pose.orientation, 0.0, false, None).with_avoidance(Avoidance {
+            avoid_ball: true,
+            avoid_placement: true,
+            ..Default::default()
+        }),
+    )
+}
+
+/// The `BallPlacer` struct represents a strategy placing the ball at the position designated
+/// by the referee for an ally ball placement.
+///
+/// The placer gets the ball and dribbles it to the designated position, then leaves it. For
+/// long placements the placer passes the ball to the supporter waiting at the designated
+/// position, which then adjusts the ball with the dribbler. Otherwise the supporter stays out
+/// of the placement corridor. The strategy never ends, the placement being over when the
+/// referee restarts the game.
+#[derive(Default)]
+pub struct BallPlacer {
+    /// The id of the placer, if it is on the field.
+    id: Option<u8>,
+    /// The id of the supporter, if it is on the field.
+    support: Option<u8>,
+    /// The id of the robot receiving the pass, for a long placement.
+    receiver: Option<u8>,
+    /// Whether the placement was planned.
+    started: bool,
+}
+
+impl BallPlacer {
+    /// Creates a new BallPlacer instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pushes the orders carrying the ball held by the robot to the designated position, then
+    /// leaving it.
+    fn place(action_wrapper: &mut ActionWrapper, id: u8, from: Point2<f64>, to: Point2<f64>) {
+        let direction = (to - from)
+            .try_normalize(f64::EPSILON)
+            .unwrap_or_else(Vector2::x);
+        let retreat = to - direction * (ROBOT_RADIUS + BALL_RADIUS + LEAVE_DISTANCE);
+        action_wrapper.push(id, DribbleTo::new(to));
+        action_wrapper.push(id, RawOrder::new(Command::default()));
+        action_wrapper.push(
+            id,
+            MoveTo::new(retreat, direction.y.atan2(direction.x), 0.0, false, None).with_avoidance(
+                Avoidance {
+                    touch_ball: false,
+                    ..Default::default()
+                },
+            ),
+        );
+    }
+}
+
+impl Strategy for BallPlacer {
+    fn name(&self) -> &'static str {
+        "BallPlacer"
+    }
+
+    fn roles(&self, _world: &World) -> Vec<Role> {
+        vec![Role::Attacker, Role::Support]
+    }
+
+    fn set_ids(&mut self, ids: Vec<Option<u8>>) {
+        let id = ids.first().copied().flatten();
+        let support = ids.get(1).copied().flatten();
+        if id != self.id || support != self.support {
+            self.started = false;
+            self.receiver = None;
+        }
+        self.id = id;
+        self.support = support;
+    }
+
+    /// Plans the placement when the robots are idle and the ball is not placed yet, and keeps
+    /// the supporter out of the way when it does not receive the ball.
+    fn step(
+        &mut self,
+        world: &World,
+        _tools_data: &mut ToolData,
+        action_wrapper: &mut ActionWrapper,
+    ) -> bool {
+        let (Some(id), Some(ball), Some(designated)) =
+            (self.id, &world.ball, world.data.designated_position)
+        else {
+            return false;
+        };
+        if !world.allies_bot.contains_key(&id) {
+            return false;
+        }
+        let ball = ball.position_2d();
+        let support = self.support.filter(|id| world.allies_bot.contains_key(id));
+        if let Some(support) = support.filter(|support| Some(*support) != self.receiver) {
+            if let Some(move_to) = keep_out(world, support, ball, designated) {
+                action_wrapper.move_to(support, move_to);
+            }
+        }
+
+        let idle = action_wrapper.is_idle(id)
+            && self
+                .receiver
+                .is_none_or(|receiver| action_wrapper.is_idle(receiver));
+        let placed = (designated - ball).norm() < PLACEMENT_TOLERANCE;
+        if (self.started && !idle) || placed {
+            return false;
+        }
+
+        action_wrapper.clear(id);
+        action_wrapper.push(id, GoToBall::new(designated));
+        action_wrapper.push(id, Dribble::default());
+        self.receiver = None;
+        match support {
+            Some(support) if (designated - ball).norm() > LONG_PLACEMENT_DISTANCE => {
+                let kick_time = expected_kick_time(&world.allies_bot[&id].pose.position, &ball);
+                let contract = PassContract::new(id, support, ball, designated, kick_time);
+                let contract = contract.shared();
+                action_wrapper.push(id, Pass::new(contract.clone()));
+                action_wrapper.clear(support);
+                action_wrapper.push(support, Receive::new(contract));
+                Self::place(action_wrapper, support, ball, designated);
+                self.receiver = Some(support);
+            }
+            _ => Self::place(action_wrapper, id, ball, designated),
+        }
+        self.started = true;
+        false
+    }
+}
+
+/// The `KeepOutOfPlacement` struct represents a strategy keeping every ally robot out of the
+/// corridor between the ball and the position where the enemy team places it, as required
+/// by the rules. It runs until the manager replaces it.
+#[derive(Default)]
+pub struct KeepOutOfPlacement;
+
+impl KeepOutOfPlacement {
+    /// Creates a new KeepOutOfPlacement instance.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Strategy for KeepOutOfPlacement {
+    fn name(&self) -> &'static str {
+        "KeepOutOfPlacement"
+    }
+
+    /// Moves the ally robots standing in the placement corridor to its closest edge, and
+    /// keeps the other ones in place.
+    fn step(
+        &mut self,
+        world: &World,
+        _tools_data: &mut ToolData,
+        action_wrapper: &mut ActionWrapper,
+    ) -> bool {
+        let (Some(ball), Some(designated)) = (&world.ball, world.data.designated_position) else {
+            return false;
+        };
+        let ball = ball.position_2d();
+        for id in world.allies_bot.keys() {
+            if let Some(move_to) = keep_out(world, *id, ball, designated) {
+                action_wrapper.move_to(*id, move_to);
+            }
+        }
+        false
+    }
+}
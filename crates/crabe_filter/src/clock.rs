@@ -0,0 +1,229 @@
+use crate::constant::{
+    CAMERA_OFFSET_RESET, CAMERA_OFFSET_SMOOTHING, CLOCK_REPORT_PERIOD, CLOCK_WINDOW_SIZE,
+};
+use chrono::{DateTime, Duration, Utc};
+use log::info;
+use std::collections::{HashMap, VecDeque};
+use std::time::Instant;
+
+/// Latency statistics of a camera in seconds, between the capture of a frame and its
+/// reception by the software.
+#[derive(Clone, Debug, Default)]
+pub struct LatencyStats {
+    /// The mean latency.
+    pub mean: f64,
+    /// The minimal latency.
+    pub min: f64,
+    /// The maximal latency.
+    pub max: f64,
+    /// The standard deviation of the latency.
+    pub jitter: f64,
+}
+
+#[derive(Default)]
+struct CameraClock {
+    /// The estimated offset in seconds between the vision clock and the camera hardware clock.
+    offset: Option<f64>,
+    /// The last measured latencies in seconds.
+    latencies: VecDeque<f64>,
+    stats: LatencyStats,
+}
+
+impl CameraClock {
+    /// Returns the capture time in the vision clock, using the camera hardware timestamp
+    /// when available as it does not suffer from the jitter of the image transfer.
+    fn capture_time(&mut self, t_capture: f64, t_capture_camera: Option<f64>) -> f64 {
+        let Some(t_camera) = t_capture_camera else {
+            return t_capture;
+        };
+
+        let measured = t_capture - t_camera;
+        let offset = match self.offset {
+            Some(offset) if (measured - offset).abs() < CAMERA_OFFSET_RESET => {
+                offset + CAMERA_OFFSET_SMOOTHING * (measured - offset)
+            }
+            _ => measured,
+        };
+        self.offset = Some(offset);
+        t_camera + offset
+    }
+
+    fn record_latency(&mut self, latency: f64) {
+        self.latencies.push_back(latency);
+        if self.latencies.len() > CLOCK_WINDOW_SIZE {
+            self.latencies.pop_front();
+        }
+
+        let count = self.latencies.len() as f64;
+        let mean = self.latencies.iter().sum::<f64>() / count;
+        let variance = self
+            .latencies
+            .iter()
+            .map(|l| (l - mean).powi(2))
+            .sum::<f64>()
+            / count;
+        self.stats = LatencyStats {
+            mean,
+            min: self.latencies.iter().copied().fold(f64::INFINITY, f64::min),
+            max: self
+                .latencies
+                .iter()
+                .copied()
+                .fold(f64::NEG_INFINITY, f64::max),
+            jitter: variance.sqrt(),
+        };
+    }
+}
+
+/// The `VisionClock` maps the timestamps of the vision frames, expressed with the clock of
+/// the vision software, into a local monotonic timeline.
+///
+/// The offset between both clocks is estimated from the lower envelope of the difference
+/// between the local reception time and the vision sending time (the transmission delay is
+/// always positive), corrected by the drift estimated with a linear regression. The offset,
+/// the drift and the latencies of the cameras are logged every `CLOCK_REPORT_PERIOD`.
+pub struct VisionClock {
+    /// The monotonic origin of the local timeline.
+    origin: Instant,
+    /// The date of the origin of the local timeline.
+    origin_date: DateTime<Utc>,
+    /// The last samples of local reception time and raw clock offset, in seconds.
+    samples: VecDeque<(f64, f64)>,
+    /// The estimated offset in seconds to add to a vision time to get a local time.
+    offset: f64,
+    /// The estimated drift of the local clock relatively to the vision clock, in s.s-1.
+    drift: f64,
+    cameras: HashMap<u32, CameraClock>,
+    /// The local time in seconds of the last report of the clock statistics.
+    last_report: Option<f64>,
+}
+
+impl Default for VisionClock {
+    fn default() -> Self {
+        Self {
+            origin: Instant::now(),
+            origin_date: Utc::now(),
+            samples: VecDeque::with_capacity(CLOCK_WINDOW_SIZE),
+            offset: 0.0,
+            drift: 0.0,
+            cameras: HashMap::new(),
+            last_report: None,
+        }
+    }
+}
+
+impl VisionClock {
+    /// Returns the current time of the local timeline.
+    pub fn now(&self) -> DateTime<Utc> {
+        self.to_date(self.local_time())
+    }
+
+    /// Returns the estimated offset in seconds between the vision clock and the local clock.
+    pub fn offset(&self) -> f64 {
+        self.offset
+    }
+
+    /// Returns the estimated drift between the vision clock and the local clock.
+    pub fn drift(&self) -> f64 {
+        self.drift
+    }
+
+    /// Returns the latency statistics of every camera.
+    pub fn latencies(&self) -> impl Iterator<Item = (&u32, &LatencyStats)> {
+        self.cameras.iter().map(|(id, camera)| (id, &camera.stats))
+    }
+
+    /// Registers a vision frame received now and returns its capture time in the local timeline.
+    ///
+    /// # Arguments
+    ///
+    /// * `camera_id`: The id of the camera that captured the frame.
+    /// * `t_capture`: The capture time of the frame, in seconds of the vision clock.
+    /// * `t_sent`: The sending time of the frame, in seconds of the vision clock.
+    /// * `t_capture_camera`: The capture time of the frame given by the camera hardware, if any.
+    pub fn synchronize(
+        &mut self,
+        camera_id: u32,
+        t_capture: f64,
+        t_sent: f64,
+        t_capture_camera: Option<f64>,
+    ) -> DateTime<Utc> {
+        let now = self.local_time();
+        self.samples.push_back((now, now - t_sent));
+        if self.samples.len() > CLOCK_WINDOW_SIZE {
+            self.samples.pop_front();
+        }
+        self.estimate(now);
+
+        let camera = self.cameras.entry(camera_id).or_default();
+        let capture = camera.capture_time(t_capture, t_capture_camera) + self.offset;
+        let capture = capture.min(now);
+        camera.record_latency(now - capture);
+
+        if self
+            .last_report
+            .is_none_or(|report| now - report >= CLOCK_REPORT_PERIOD)
+        {
+            self.last_report = Some(now);
+            self.report();
+        }
+        self.to_date(capture)
+    }
+
+    /// Logs the estimated offset and drift of the vision clock and the latency statistics
+    /// of every camera.
+    fn report(&self) {
+        info!(
+            "Vision clock offset {:.4} s, drift {:.2e} s/s",
+            self.offset(),
+            self.drift()
+        );
+        let mut latencies: Vec<_> = self.latencies().collect();
+        latencies.sort_by_key(|(id, _)| **id);
+        for (id, stats) in latencies {
+            info!(
+                "Camera {} latency: mean {:.1} ms, min {:.1} ms, max {:.1} ms, jitter {:.1} ms",
+                id,
+                stats.mean * 1e3,
+                stats.min * 1e3,
+                stats.max * 1e3,
+                stats.jitter * 1e3
+            );
+        }
+    }
+
+    fn estimate(&mut self, now: f64) {
+        let count = self.samples.len() as f64;
+        let (mean_time, mean_offset) = self
+            .samples
+            .iter()
+            .fold((0.0, 0.0), |(t, o), (time, offset)| (t + time, o + offset));
+        let (mean_time, mean_offset) = (mean_time / count, mean_offset / count);
+        let (covariance, variance) =
+            self.samples
+                .iter()
+                .fold((0.0, 0.0), |(c, v), (time, offset)| {
+                    (
+                        c + (time - mean_time) * (offset - mean_offset),
+                        v + (time - mean_time).powi(2),
+                    )
+                });
+        if variance > f64::EPSILON {
+            self.drift = covariance / variance;
+        }
+
+        self.offset = self
+            .samples
+            .iter()
+            .map(|(time, offset)| offset + self.drift * (now - time))
+            .fold(f64::INFINITY, f64::min);
+    }
+
+    fn local_time(&self) -> f64 {
+        self.origin.elapsed().as_secs_f64()
+    }
+
+    fn to_date(&self, local_time: f64) -> DateTime<Utc> {
+        self.origin_date + Duration::nanoseconds((local_time * 1e9) as i64)
+    }
+}
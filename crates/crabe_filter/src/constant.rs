@@ -2,3 +2,98 @@ use std::time::Duration;
 
 pub const PACKET_BUFFER_SIZE: usize = 64;
 pub const ROBOT_TIMEOUT: Duration = Duration::from_secs(2);
+/// The distance in meters the ball has to travel after a restart to consider it as kicked.
+pub const BALL_MOVED_DISTANCE: f64 = 0.05;
+
+/// Variance of the measured robot positions, in squared meters.
+pub const ROBOT_POSITION_NOISE: f64 = 1e-5;
+/// Variance of the measured robot orientations, in squared radians.
+pub const ROBOT_ORIENTATION_NOISE: f64 = 4e-4;
+/// Variance of the random linear acceleration of a robot, in squared m.s-2.
+pub const ROBOT_ACCELERATION_NOISE: f64 = 9.0;
+/// Variance of the random angular acceleration of a robot, in squared rad.s-2.
+pub const ROBOT_ANGULAR_ACCELERATION_NOISE: f64 = 25.0;
+/// Variance of the measured ball positions, in squared meters.
+pub const BALL_POSITION_NOISE: f64 = 1e-5;
+/// Variance of the random acceleration of the ball, in squared m.s-2.
+pub const BALL_ACCELERATION_NOISE: f64 = 10.0;
+/// Variance of the velocity of a newly detected object, in squared m.s-1.
+pub const INITIAL_VELOCITY_VARIANCE: f64 = 1.0;
+
+/// Maximum difference between the capture times of detections from different cameras
+/// to merge them into a single measurement.
+pub const FUSION_WINDOW: Duration = Duration::from_millis(8);
+/// Maximum distance in meters between two balls seen by different cameras to merge them.
+pub const BALL_MERGE_DISTANCE: f64 = 0.1;
+/// Number of frames behind the last received frame from which a camera is considered as
+/// restarted instead of late.
+pub const FRAME_RESET_GAP: u32 = 60;
+
+/// Number of samples kept to estimate the vision clock offset and the camera latencies.
+pub const CLOCK_WINDOW_SIZE: usize = 600;
+/// Period in seconds between two logs of the vision clock and camera latency statistics.
+pub const CLOCK_REPORT_PERIOD: f64 = 10.0;
+/// Smoothing factor of the offset between the vision clock and a camera hardware clock.
+pub const CAMERA_OFFSET_SMOOTHING: f64 = 0.05;
+/// Difference in seconds from which a camera hardware clock is considered as reset.
+pub const CAMERA_OFFSET_RESET: f64 = 0.1;
+
+/// Radius in meters of a robot when the geometry does not provide it.
+pub const DEFAULT_ROBOT_RADIUS: f64 = 0.09;
+/// Radius in meters of the ball when the geometry does not provide it.
+pub const DEFAULT_BALL_RADIUS: f64 = 0.0215;
+/// Maximum number of ball hypotheses tracked at the same time.
+pub const BALL_MAX_HYPOTHESES: usize = 10;
+/// Distance in meters around the predicted position of a ball hypothesis in which a
+/// detection is associated to it, in addition to the distance the ball can travel.
+pub const BALL_GATE_DISTANCE: f64 = 0.2;
+/// Maximum speed of the ball in m.s-1, used to reject impossible associations.
+pub const BALL_MAX_SPEED: f64 = 10.0;
+/// Number of detections required before a ball hypothesis can be selected.
+pub const BALL_CONFIRMATION_DETECTIONS: u32 = 3;
+/// Number of detections from which a ball hypothesis is fully trusted.
+pub const BALL_MAX_DETECTIONS: u32 = 30;
+/// Factor applied to the plausibility of the selected ball hypothesis to avoid switching
+/// between hypotheses.
+pub const BALL_SELECTION_HYSTERESIS: f64 = 2.0;
+/// Rolling deceleration of the ball in m.s-2, used to predict the position of a hidden ball.
+pub const BALL_ROLLING_DECELERATION: f64 = 0.5;
+/// Distance in meters between a robot and the ball under which the robot may hide the ball.
+pub const BALL_OCCLUSION_MARGIN: f64 = 0.05;
+/// Delay without detection after which the ball is considered as occluded.
+pub const BALL_OCCLUDED_DELAY: Duration = Duration::from_millis(100);
+/// Delay without detection after which the ball is considered as lost.
+pub const BALL_LOST_TIMEOUT: Duration = Duration::from_secs(1);
+/// Delay without detection after which a ball hidden by a robot is considered as lost.
+pub const BALL_HIDDEN_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Gravitational acceleration in m.s-2.
+pub const GRAVITY: f64 = 9.81;
+/// Speed of the ball in m.s-1 above which the detections are used to look for a chip kick.
+pub const CHIP_MIN_SPEED: f64 = 1.0;
+/// Distance in meters between a detection and the estimated ball position to use it in the
+/// chip estimation. It is large because the projection of a high ball is far from the ball.
+pub const CHIP_GATE_DISTANCE: f64 = 1.0;
+/// Number of detections required to estimate a chip trajectory.
+pub const CHIP_MIN_SAMPLES: usize = 8;
+/// Maximum number of detections used to estimate a chip trajectory.
+pub const CHIP_MAX_SAMPLES: usize = 120;
+/// Minimal initial vertical speed in m.s-1 of a chip kick.
+pub const CHIP_MIN_VERTICAL_SPEED: f64 = 0.5;
+/// Maximal mean error in meters between the detections and the projected chip trajectory.
+pub const CHIP_MAX_RESIDUAL: f64 = 0.02;
+/// Ratio of the error of the ground trajectory the error of the chip trajectory must be below.
+pub const CHIP_RESIDUAL_RATIO: f64 = 0.5;
+
+/// Visibility of a tracked ball under which it is considered as occluded.
+pub const TRACKER_MIN_VISIBILITY: f32 = 0.5;
+
+/// Delay without feedback after which the information reported by a robot is discarded.
+pub const FEEDBACK_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Distance in meters between the ball and the dribbler of a robot under which the robot
+/// starts to hold the ball.
+pub const POSSESSION_DISTANCE: f64 = 0.04;
+/// Distance in meters between the ball and the dribbler of a robot above which the robot
+/// does not hold the ball anymore.
+pub const POSSESSION_RELEASE_DISTANCE: f64 = 0.07;
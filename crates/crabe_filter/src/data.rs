@@ -1,10 +1,11 @@
 pub mod camera;
 
+use crate::clock::VisionClock;
 use crate::constant;
 use crate::data::camera::{CamBall, CamGeometry, CamRobot};
 use chrono::{DateTime, Utc};
 use constant::PACKET_BUFFER_SIZE;
-use crabe_framework::data::world::{AllyInfo, Ball, EnemyInfo, Robot};
+use crabe_framework::data::world::{AllyInfo, Ball, EnemyInfo, GameData, Robot};
 use ringbuffer::ConstGenericRingBuffer;
 use std::collections::HashMap;
 use std::time::Instant;
@@ -14,6 +15,9 @@ pub struct FrameInfo {
     pub camera_id: u32,
     pub frame_number: u32,
     pub t_capture: DateTime<Utc>,
+    /// Whether the detection merges the detections of several cameras, in which case
+    /// `camera_id` is only the camera with the largest weight.
+    pub fused: bool,
 }
 
 pub type TrackedRobotMap<T> = HashMap<u8, TrackedRobot<T>>;
@@ -23,6 +27,8 @@ pub struct FilterData {
     pub enemies: TrackedRobotMap<EnemyInfo>,
     pub ball: TrackedBall,
     pub geometry: CamGeometry,
+    pub game_data: GameData,
+    pub clock: VisionClock,
 }
 
 pub struct TrackedRobot<T> {
@@ -45,6 +51,8 @@ pub struct TrackedBall {
     pub packets: ConstGenericRingBuffer<CamBall, PACKET_BUFFER_SIZE>,
     pub data: Ball,
     pub last_update: Instant,
+    /// Whether the ball has not been seen for too long to predict its position.
+    pub lost: bool,
 }
 
 impl Default for TrackedBall {
@@ -53,6 +61,7 @@ impl Default for TrackedBall {
             packets: ConstGenericRingBuffer::new(),
             last_update: Instant::now(),
             data: Default::default(),
+            lost: true,
         }
     }
 }
@@ -32,6 +32,13 @@ pub struct CamFieldArc {
     pub arc: Arc,
 }
 
+#[derive(Debug)]
+pub struct CamCalibration {
+    pub camera_id: u32,
+    pub focal_length: f64,
+    pub position: Option<Point3<f64>>,
+}
+
 #[derive(Debug, Default)]
 pub struct CamGeometry {
     pub field_length: f64,
@@ -49,4 +56,5 @@ pub struct CamGeometry {
     pub goal_height: Option<f64>,
     pub ball_radius: Option<f64>,
     pub max_robot_radius: Option<f64>,
+    pub calibrations: HashMap<u32, CamCalibration>,
 }
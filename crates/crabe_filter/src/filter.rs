@@ -1,4 +1,7 @@
+pub mod chip;
+pub mod fusion;
 pub mod inactive;
+pub mod kalman;
 pub mod passthrough;
 pub mod velocity_acceleration;
 
@@ -0,0 +1,240 @@
+use crate::constant::{
+    CHIP_GATE_DISTANCE, CHIP_MAX_RESIDUAL, CHIP_MAX_SAMPLES, CHIP_MIN_SAMPLES, CHIP_MIN_SPEED,
+    CHIP_MIN_VERTICAL_SPEED, CHIP_RESIDUAL_RATIO, GRAVITY,
+};
+use crate::data::camera::CamGeometry;
+use crate::data::FilterData;
+use crate::filter::Filter;
+use chrono::{DateTime, Duration, Utc};
+use crabe_framework::data::world::{BallFlight, World};
+use nalgebra::{Point2, Point3, SMatrix, SVector};
+use ringbuffer::RingBuffer;
+
+/// A detection of the ball, which is the projection on the ground of the ball seen by a camera.
+struct Sample {
+    /// The time of the detection in seconds since the first sample.
+    time: f64,
+    projection: Point2<f64>,
+    camera: Point3<f64>,
+}
+
+/// A parabolic trajectory of the ball, starting at the time of the first sample.
+struct Trajectory {
+    position: Point3<f64>,
+    velocity: SVector<f64, 3>,
+}
+
+impl Trajectory {
+    fn position(&self, time: f64) -> Point3<f64> {
+        let mut position = self.position + self.velocity * time;
+        position.z -= 0.5 * GRAVITY * time.powi(2);
+        position
+    }
+
+    /// Returns the time at which the ball touches the ground.
+    fn landing_time(&self) -> f64 {
+        let vz = self.velocity.z;
+        (vz + (vz.powi(2) + 2.0 * GRAVITY * self.position.z.max(0.0)).sqrt()) / GRAVITY
+    }
+
+    fn max_height(&self) -> f64 {
+        self.position.z + self.velocity.z.max(0.0).powi(2) / (2.0 * GRAVITY)
+    }
+
+    /// Returns the mean distance between the samples and the projection of the trajectory.
+    fn residual(&self, samples: &[Sample]) -> f64 {
+        samples
+            .iter()
+            .map(|s| (project(&self.position(s.time), &s.camera) - s.projection).norm())
+            .sum::<f64>()
+            / samples.len() as f64
+    }
+}
+
+/// Returns the projection on the ground of a point seen by a camera.
+fn project(point: &Point3<f64>, camera: &Point3<f64>) -> Point2<f64> {
+    let scale = camera.z / (camera.z - point.z);
+    camera.xy() + (point.xy() - camera.xy()) * scale
+}
+
+/// Returns the ground position of a point from its projection and its height.
+fn unproject(projection: &Point2<f64>, height: f64, camera: &Point3<f64>) -> Point2<f64> {
+    camera.xy() + (projection - camera.xy()) * ((camera.z - height) / camera.z)
+}
+
+/// Solves a linear system in the least squares sense with the normal equations.
+fn least_squares<const N: usize>(
+    rows: impl Iterator<Item = (SVector<f64, N>, f64)>,
+) -> Option<SVector<f64, N>> {
+    let (ata, atb) = rows.fold(
+        (SMatrix::<f64, N, N>::zeros(), SVector::<f64, N>::zeros()),
+        |(ata, atb), (row, value)| (ata + row * row.transpose(), atb + row * value),
+    );
+    ata.try_inverse().map(|inverse| inverse * atb)
+}
+
+/// Fits a parabolic trajectory to the samples.
+///
+/// The projection `p` of a ball at `(x, y, z)` seen by a camera at `c` verifies
+/// `(x, y) * c.z + z * (p - c.xy) = p * c.z`, which is linear in the parameters
+/// `(x0, y0, vx, vy, z0, vz)` of the trajectory.
+fn fit_chip(samples: &[Sample]) -> Option<Trajectory> {
+    let rows = samples.iter().flat_map(|s| {
+        let t = s.time;
+        let h = s.camera.z;
+        let d = s.projection - s.camera.xy();
+        let gravity = 0.5 * GRAVITY * t.powi(2);
+        [
+            (
+                SVector::<f64, 6>::from([h, 0.0, h * t, 0.0, d.x, d.x * t]),
+                s.projection.x * h + gravity * d.x,
+            ),
+            (
+                SVector::<f64, 6>::from([0.0, h, 0.0, h * t, d.y, d.y * t]),
+                s.projection.y * h + gravity * d.y,
+            ),
+        ]
+    });
+
+    least_squares(rows).map(|u| Trajectory {
+        position: Point3::new(u[0], u[1], u[4]),
+        velocity: SVector::<f64, 3>::new(u[2], u[3], u[5]),
+    })
+}
+
+/// Fits a straight trajectory on the ground to the samples.
+fn fit_ground(samples: &[Sample]) -> Option<Trajectory> {
+    let rows = samples.iter().flat_map(|s| {
+        let t = s.time;
+        [
+            (SVector::<f64, 4>::from([1.0, 0.0, t, 0.0]), s.projection.x),
+            (SVector::<f64, 4>::from([0.0, 1.0, 0.0, t]), s.projection.y),
+        ]
+    });
+
+    least_squares(rows).map(|u| Trajectory {
+        position: Point3::new(u[0], u[1], 0.0),
+        velocity: SVector::<f64, 3>::new(u[2], u[3], 0.0),
+    })
+}
+
+fn camera_position(geometry: &CamGeometry, camera_id: u32) -> Option<Point3<f64>> {
+    geometry
+        .calibrations
+        .get(&camera_id)
+        .and_then(|calibration| calibration.position)
+        .filter(|camera| camera.z > 0.0)
+}
+
+/// The `ChipFilter` detects chip kicks by fitting a parabolic trajectory to the detections
+/// of a fast ball, using the known position of the cameras.
+///
+/// While the ball is in the air, the detections are moved from their projection on the ground
+/// to the position below the ball, and their height is set, so that the tracker follows the
+/// real ball. The detections fused from several cameras cannot be back-projected through a
+/// single camera: they are left out of the fit, and replaced by the position of the ball on
+/// the trajectory. It must run before the filter that estimates the state of the ball.
+#[derive(Default)]
+pub struct ChipFilter {
+    /// The capture time of the first sample.
+    origin: Option<DateTime<Utc>>,
+    samples: Vec<Sample>,
+    trajectory: Option<Trajectory>,
+}
+
+impl ChipFilter {
+    fn reset(&mut self) {
+        self.origin = None;
+        self.samples.clear();
+        self.trajectory = None;
+    }
+
+    fn seconds_since_origin(origin: DateTime<Utc>, time: DateTime<Utc>) -> f64 {
+        (time - origin).num_microseconds().unwrap_or(0) as f64 / 1e6
+    }
+
+    fn estimate(&mut self) {
+        if self.samples.len() < CHIP_MIN_SAMPLES {
+            return;
+        }
+
+        let chip = fit_chip(&self.samples);
+        let ground_residual = fit_ground(&self.samples)
+            .map_or(f64::INFINITY, |ground| ground.residual(&self.samples));
+        self.trajectory = chip.filter(|chip| {
+            let residual = chip.residual(&self.samples);
+            chip.velocity.z > CHIP_MIN_VERTICAL_SPEED
+                && residual < CHIP_MAX_RESIDUAL
+                && residual < ground_residual * CHIP_RESIDUAL_RATIO
+        });
+    }
+}
+
+impl Filter for ChipFilter {
+    fn step(&mut self, filter_data: &mut FilterData, _world: &World) {
+        let ball = &mut filter_data.ball;
+        if ball.lost || ball.data.velocity.norm() < CHIP_MIN_SPEED {
+            self.reset();
+            ball.data.flight = None;
+            return;
+        }
+
+        let geometry = &filter_data.geometry;
+        let ball_position = ball.data.position_2d();
+        for packet in ball.packets.iter_mut() {
+            if packet.frame_info.fused {
+                if let (Some(origin), Some(trajectory)) = (self.origin, &self.trajectory) {
+                    let time = Self::seconds_since_origin(origin, packet.frame_info.t_capture);
+                    let position = trajectory.position(time);
+                    packet.position = Point3::new(position.x, position.y, position.z.max(0.0));
+                }
+                continue;
+            }
+            let Some(camera) = camera_position(geometry, packet.frame_info.camera_id) else {
+                continue;
+            };
+            if (packet.position.xy() - ball_position).norm() > CHIP_GATE_DISTANCE {
+                continue;
+            }
+
+            let origin = *self.origin.get_or_insert(packet.frame_info.t_capture);
+            let time = Self::seconds_since_origin(origin, packet.frame_info.t_capture);
+            self.samples.push(Sample {
+                time,
+                projection: packet.position.xy(),
+                camera,
+            });
+            if self.samples.len() > CHIP_MAX_SAMPLES {
+                self.samples.remove(0);
+            }
+
+            if let Some(trajectory) = &self.trajectory {
+                let height = trajectory.position(time).z.max(0.0);
+                let position = unproject(&packet.position.xy(), height, &camera);
+                packet.position = Point3::new(position.x, position.y, height);
+            }
+        }
+
+        self.estimate();
+
+        let (Some(origin), Some(trajectory)) = (self.origin, &self.trajectory) else {
+            ball.data.flight = None;
+            return;
+        };
+        let landing_time = trajectory.landing_time();
+        let landing_date = origin + Duration::microseconds((landing_time * 1e6) as i64);
+        if landing_date < filter_data.clock.now() {
+            // The ball bounced, the next bounces are detected as new chips.
+            self.reset();
+            ball.data.flight = None;
+            return;
+        }
+
+        let landing = trajectory.position(landing_time);
+        ball.data.flight = Some(BallFlight {
+            landing_position: Point2::new(landing.x, landing.y),
+            landing_time: landing_date,
+            max_height: trajectory.max_height(),
+        });
+    }
+}
@@ -0,0 +1,226 @@
+use crate::constant::{BALL_MERGE_DISTANCE, FRAME_RESET_GAP, FUSION_WINDOW};
+use crate::data::camera::{CamBall, CamGeometry, CamRobot};
+use crate::data::{FilterData, FrameInfo};
+use crate::filter::Filter;
+use crabe_framework::data::world::World;
+use nalgebra::{Point2, Point3};
+use ringbuffer::RingBuffer;
+use std::collections::HashMap;
+
+/// Returns the quality of the calibration of a camera for an object at the given position.
+///
+/// The precision of a detection decreases with the distance between the object and the
+/// point below the camera, so the quality goes from 1 (below the camera) towards 0.
+/// Cameras without a known position have a quality of 1.
+fn calibration_quality(geometry: &CamGeometry, camera_id: u32, position: &Point2<f64>) -> f64 {
+    geometry
+        .calibrations
+        .get(&camera_id)
+        .and_then(|calibration| calibration.position)
+        .filter(|camera| camera.z > 0.0)
+        .map_or(1.0, |camera| {
+            let distance = (position - camera.xy()).norm();
+            1.0 / (1.0 + (distance / camera.z).powi(2))
+        })
+}
+
+/// Returns the weight of a detection in the fusion.
+fn weight(
+    geometry: &CamGeometry,
+    frame_info: &FrameInfo,
+    confidence: f64,
+    position: &Point2<f64>,
+) -> f64 {
+    confidence.max(f64::EPSILON) * calibration_quality(geometry, frame_info.camera_id, position)
+}
+
+/// Merges the frame information of a group of weighted detections.
+fn merge_frame_info<'a>(frames: impl Iterator<Item = (&'a FrameInfo, f64)>) -> FrameInfo {
+    let frames = frames.collect::<Vec<_>>();
+    let (best, _) = frames
+        .iter()
+        .max_by(|(_, w1), (_, w2)| w1.total_cmp(w2))
+        .expect("cannot merge an empty group of detections");
+    let total: f64 = frames.iter().map(|(_, w)| w).sum();
+    let offset: f64 = frames
+        .iter()
+        .map(|(f, w)| {
+            (f.t_capture - best.t_capture)
+                .num_microseconds()
+                .unwrap_or(0) as f64
+                * w
+        })
+        .sum::<f64>()
+        / total;
+
+    FrameInfo {
+        camera_id: best.camera_id,
+        frame_number: best.frame_number,
+        t_capture: best.t_capture + chrono::Duration::microseconds(offset as i64),
+        fused: frames.len() > 1,
+    }
+}
+
+/// Splits time-sorted detections into groups of detections seen by distinct cameras at
+/// the same time.
+fn group_by_time<T>(
+    detections: Vec<T>,
+    frame_info: impl Fn(&T) -> &FrameInfo,
+    same_object: impl Fn(&T, &T) -> bool,
+) -> Vec<Vec<T>> {
+    let mut groups: Vec<Vec<T>> = vec![];
+    for detection in detections {
+        let frame = frame_info(&detection);
+        let group = groups.iter_mut().find(|group| {
+            let first = frame_info(&group[0]);
+            // Use std duration as chrono does not support const fn yet
+            (frame.t_capture - first.t_capture)
+                .abs()
+                .to_std()
+                .is_ok_and(|d| d <= FUSION_WINDOW)
+                && group.iter().all(|d| {
+                    frame_info(d).camera_id != frame.camera_id && same_object(d, &detection)
+                })
+        });
+
+        match group {
+            Some(group) => group.push(detection),
+            None => groups.push(vec![detection]),
+        }
+    }
+    groups
+}
+
+fn fuse_robots(robots: Vec<CamRobot>, geometry: &CamGeometry) -> Option<CamRobot> {
+    let weights = robots
+        .iter()
+        .map(|r| weight(geometry, &r.frame_info, r.confidence, &r.position))
+        .collect::<Vec<_>>();
+    let total: f64 = weights.iter().sum();
+
+    let position = robots
+        .iter()
+        .zip(&weights)
+        .fold(Point2::origin(), |acc, (r, w)| {
+            acc + r.position.coords * (w / total)
+        });
+    // The orientations are averaged on the unit circle to handle the wrap around ±π.
+    let (sin, cos) = robots
+        .iter()
+        .zip(&weights)
+        .fold((0.0, 0.0), |(sin, cos), (r, w)| {
+            (sin + w * r.orientation.sin(), cos + w * r.orientation.cos())
+        });
+
+    Some(CamRobot {
+        id: robots.first()?.id,
+        frame_info: merge_frame_info(
+            robots
+                .iter()
+                .map(|r| &r.frame_info)
+                .zip(weights.iter().copied()),
+        ),
+        position,
+        orientation: sin.atan2(cos),
+        confidence: robots.iter().map(|r| r.confidence).fold(0.0, f64::max),
+    })
+}
+
+fn fuse_balls(balls: Vec<CamBall>, geometry: &CamGeometry) -> Option<CamBall> {
+    let weights = balls
+        .iter()
+        .map(|b| weight(geometry, &b.frame_info, b.confidence, &b.position.xy()))
+        .collect::<Vec<_>>();
+    let total: f64 = weights.iter().sum();
+
+    let position = balls
+        .iter()
+        .zip(&weights)
+        .fold(Point3::origin(), |acc, (b, w)| {
+            acc + b.position.coords * (w / total)
+        });
+
+    Some(CamBall {
+        frame_info: merge_frame_info(
+            balls
+                .iter()
+                .map(|b| &b.frame_info)
+                .zip(weights.iter().copied()),
+        ),
+        position,
+        confidence: balls.iter().map(|b| b.confidence).fold(0.0, f64::max),
+    })
+}
+
+/// The `CameraFusionFilter` merges the detections of the same object seen at the same time by
+/// multiple cameras into a single weighted measurement, and drops the detections coming from
+/// frames older than the last frame received from the same camera.
+///
+/// It must run before the filter that estimates the state of the objects.
+#[derive(Default)]
+pub struct CameraFusionFilter {
+    /// The number of the last frame handled for each camera.
+    last_frames: HashMap<u32, u32>,
+}
+
+impl CameraFusionFilter {
+    /// Returns whether the frame is older than the last frame handled from the same camera.
+    /// A frame number far behind the last one means that the vision software was restarted.
+    fn is_stale(&self, frame_info: &FrameInfo) -> bool {
+        self.last_frames
+            .get(&frame_info.camera_id)
+            .is_some_and(|&last| {
+                frame_info.frame_number <= last && last - frame_info.frame_number < FRAME_RESET_GAP
+            })
+    }
+
+    fn fresh<T>(&self, detections: Vec<T>, frame_info: impl Fn(&T) -> &FrameInfo) -> Vec<T> {
+        let mut detections = detections
+            .into_iter()
+            .filter(|d| !self.is_stale(frame_info(d)))
+            .collect::<Vec<_>>();
+        detections.sort_by_key(|d| frame_info(d).t_capture);
+        detections
+    }
+}
+
+impl Filter for CameraFusionFilter {
+    fn step(&mut self, filter_data: &mut FilterData, _world: &World) {
+        let mut last_frames = self.last_frames.clone();
+        let mut handled = |frame_info: &FrameInfo| {
+            last_frames
+                .entry(frame_info.camera_id)
+                .and_modify(|last| *last = frame_info.frame_number.max(*last))
+                .or_insert(frame_info.frame_number);
+        };
+
+        let geometry = &filter_data.geometry;
+        let robots = filter_data
+            .allies
+            .values_mut()
+            .map(|r| &mut r.packets)
+            .chain(filter_data.enemies.values_mut().map(|r| &mut r.packets));
+        for packets in robots {
+            let detections = self.fresh(packets.drain().collect(), |r: &CamRobot| &r.frame_info);
+            detections.iter().for_each(|r| handled(&r.frame_info));
+            group_by_time(detections, |r| &r.frame_info, |_, _| true)
+                .into_iter()
+                .filter_map(|group| fuse_robots(group, geometry))
+                .for_each(|r| packets.push(r));
+        }
+
+        let packets = &mut filter_data.ball.packets;
+        let detections = self.fresh(packets.drain().collect(), |b: &CamBall| &b.frame_info);
+        detections.iter().for_each(|b| handled(&b.frame_info));
+        group_by_time(
+            detections,
+            |b| &b.frame_info,
+            |b1, b2| (b1.position.xy() - b2.position.xy()).norm() < BALL_MERGE_DISTANCE,
+        )
+        .into_iter()
+        .filter_map(|group| fuse_balls(group, geometry))
+        .for_each(|b| packets.push(b));
+
+        self.last_frames = last_frames;
+    }
+}
@@ -34,7 +34,7 @@ impl Default for InactiveFilter {
 
 impl Filter for InactiveFilter {
     fn step(&mut self, filter_data: &mut FilterData, _world: &World) {
-        let now = Utc::now();
+        let now = filter_data.clock.now();
         self.purge_inactive(&mut filter_data.allies, now);
         self.purge_inactive(&mut filter_data.enemies, now);
     }
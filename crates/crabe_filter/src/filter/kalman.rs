@@ -0,0 +1,143 @@
+mod ball;
+pub use self::ball::BallTracker;
+mod model;
+pub use self::model::{ConstantVelocityModel, RobotModel};
+
+use crate::constant::{
+    BALL_ACCELERATION_NOISE, BALL_POSITION_NOISE, INITIAL_VELOCITY_VARIANCE,
+    ROBOT_ACCELERATION_NOISE, ROBOT_ANGULAR_ACCELERATION_NOISE, ROBOT_ORIENTATION_NOISE,
+    ROBOT_POSITION_NOISE, ROBOT_TIMEOUT,
+};
+use crate::data::camera::{CamBall, CamRobot};
+use crate::data::{FilterData, TrackedRobotMap};
+use crate::filter::Filter;
+use chrono::{DateTime, Utc};
+use crabe_framework::data::world::World;
+use nalgebra::{Point2, Vector2, Vector3};
+use ringbuffer::RingBuffer;
+use std::collections::HashMap;
+
+/// Position (x, y) and velocity (vx, vy) model of the ball.
+type BallModel = ConstantVelocityModel<2, 4>;
+
+/// A model with the capture time of the last measurement it was updated with.
+struct Track<M> {
+    model: M,
+    timestamp: DateTime<Utc>,
+}
+
+impl<M> Track<M> {
+    /// Returns the time elapsed in seconds between the last update and `t_capture`,
+    /// or `None` if the track is too old to be trusted.
+    fn elapsed(&self, t_capture: DateTime<Utc>) -> Option<f64> {
+        let elapsed = t_capture - self.timestamp;
+        match elapsed.to_std() {
+            Ok(d) if d < ROBOT_TIMEOUT => Some(d.as_secs_f64()),
+            Ok(_) => None,
+            // Late packets are applied without prediction, and do not move the track back in
+            // time.
+            Err(_) => Some(0.0),
+        }
+    }
+}
+
+fn new_robot_track(packet: &CamRobot) -> Track<RobotModel> {
+    Track {
+        model: RobotModel::new(
+            Vector3::new(packet.position.x, packet.position.y, packet.orientation),
+            Vector3::new(
+                ROBOT_ACCELERATION_NOISE,
+                ROBOT_ACCELERATION_NOISE,
+                ROBOT_ANGULAR_ACCELERATION_NOISE,
+            ),
+            Vector3::new(
+                ROBOT_POSITION_NOISE,
+                ROBOT_POSITION_NOISE,
+                ROBOT_ORIENTATION_NOISE,
+            ),
+            INITIAL_VELOCITY_VARIANCE,
+        ),
+        timestamp: packet.frame_info.t_capture,
+    }
+}
+
+fn update_robot_track(track: &mut Track<RobotModel>, packet: &CamRobot, dt: f64) {
+    track.model.predict(dt);
+    track.model.update(Vector3::new(
+        packet.position.x,
+        packet.position.y,
+        packet.orientation,
+    ));
+    track.timestamp = track.timestamp.max(packet.frame_info.t_capture);
+}
+
+fn new_ball_track(packet: &CamBall) -> Track<BallModel> {
+    Track {
+        model: BallModel::new(
+            Vector2::new(packet.position.x, packet.position.y),
+            Vector2::new(BALL_ACCELERATION_NOISE, BALL_ACCELERATION_NOISE),
+            Vector2::new(BALL_POSITION_NOISE, BALL_POSITION_NOISE),
+            INITIAL_VELOCITY_VARIANCE,
+        ),
+        timestamp: packet.frame_info.t_capture,
+    }
+}
+
+fn update_ball_track(track: &mut Track<BallModel>, packet: &CamBall, dt: f64) {
+    track.model.predict(dt);
+    track
+        .model
+        .update(Vector2::new(packet.position.x, packet.position.y));
+    track.timestamp = track.timestamp.max(packet.frame_info.t_capture);
+}
+
+fn filter_robots<T>(
+    tracks: &mut HashMap<u8, Track<RobotModel>>,
+    tracked_robots: &mut TrackedRobotMap<T>,
+) {
+    tracks.retain(|id, _| tracked_robots.contains_key(id));
+    tracked_robots.iter_mut().for_each(|(id, tracked)| {
+        let mut packets = tracked.packets.drain().collect::<Vec<_>>();
+        packets.sort_by_key(|p| p.frame_info.t_capture);
+
+        for packet in packets {
+            match tracks.get_mut(id) {
+                Some(track) => match track.elapsed(packet.frame_info.t_capture) {
+                    Some(dt) => update_robot_track(track, &packet, dt),
+                    None => *track = new_robot_track(&packet),
+                },
+                None => {
+                    tracks.insert(*id, new_robot_track(&packet));
+                }
+            }
+        }
+
+        if let Some(track) = tracks.get(id) {
+            let pose = track.model.pose();
+            let velocity = track.model.velocity();
+            tracked.data.pose.position = Point2::new(pose.x, pose.y);
+            tracked.data.pose.orientation = pose.z;
+            tracked.data.velocity.linear = Vector2::new(velocity.x, velocity.y);
+            tracked.data.velocity.angular = velocity.z;
+            tracked.data.timestamp = track.timestamp;
+        }
+    });
+}
+
+/// The `KalmanFilter` estimates the pose and the velocity of every robot and of the ball
+/// from all the buffered camera detections, using an extended Kalman filter for the robots.
+/// The ball is tracked by the [`BallTracker`].
+#[derive(Default)]
+pub struct KalmanFilter {
+    allies: HashMap<u8, Track<RobotModel>>,
+    enemies: HashMap<u8, Track<RobotModel>>,
+    ball: BallTracker,
+}
+
+impl Filter for KalmanFilter {
+    fn step(&mut self, filter_data: &mut FilterData, _world: &World) {
+        filter_robots(&mut self.allies, &mut filter_data.allies);
+        filter_robots(&mut self.enemies, &mut filter_data.enemies);
+        self.ball.step(filter_data);
+    }
+}
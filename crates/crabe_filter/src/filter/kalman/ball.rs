@@ -0,0 +1,234 @@
+use super::{new_ball_track, update_ball_track, BallModel, Track};
+use crate::constant::{
+    BALL_CONFIRMATION_DETECTIONS, BALL_GATE_DISTANCE, BALL_HIDDEN_TIMEOUT, BALL_LOST_TIMEOUT,
+    BALL_MAX_DETECTIONS, BALL_MAX_HYPOTHESES, BALL_MAX_SPEED, BALL_OCCLUDED_DELAY,
+    BALL_OCCLUSION_MARGIN, BALL_ROLLING_DECELERATION, BALL_SELECTION_HYSTERESIS,
+    DEFAULT_BALL_RADIUS, DEFAULT_ROBOT_RADIUS,
+};
+use crate::data::camera::{CamBall, CamGeometry};
+use crate::data::FilterData;
+use chrono::{DateTime, Utc};
+use crabe_framework::data::world::BallStatus;
+use nalgebra::{Point2, Point3, Vector2, Vector3};
+use ringbuffer::RingBuffer;
+
+/// Returns the time elapsed in seconds between `from` and `to`, or zero if `to` is before `from`.
+fn seconds_between(from: DateTime<Utc>, to: DateTime<Utc>) -> f64 {
+    (to - from).to_std().map_or(0.0, |d| d.as_secs_f64())
+}
+
+fn in_field(geometry: &CamGeometry, position: &Point2<f64>) -> bool {
+    // Without geometry, every position is accepted.
+    if geometry.field_length <= 0.0 {
+        return true;
+    }
+    position.x.abs() <= geometry.field_length / 2.0 + geometry.boundary_width
+        && position.y.abs() <= geometry.field_width / 2.0 + geometry.boundary_width
+}
+
+/// A possible ball, built from the detections that are consistent with each other.
+struct Hypothesis {
+    id: u32,
+    track: Track<BallModel>,
+    /// The number of detections associated to this hypothesis.
+    detections: u32,
+    /// The height of the last detection.
+    height: f64,
+}
+
+impl Hypothesis {
+    fn new(id: u32, packet: &CamBall) -> Self {
+        Self {
+            id,
+            track: new_ball_track(packet),
+            detections: 1,
+            height: packet.position.z,
+        }
+    }
+
+    fn position(&self) -> Point2<f64> {
+        Point2::from(self.track.model.position())
+    }
+
+    fn velocity(&self) -> Vector2<f64> {
+        self.track.model.velocity()
+    }
+
+    /// Returns the distance between the detection and the predicted position of the
+    /// hypothesis, or `None` if the ball cannot have reached the detection.
+    fn gate_distance(&self, packet: &CamBall) -> Option<f64> {
+        let dt = seconds_between(self.track.timestamp, packet.frame_info.t_capture);
+        let predicted = self.position() + self.velocity() * dt;
+        let distance = (packet.position.xy() - predicted).norm();
+        (distance < BALL_GATE_DISTANCE + BALL_MAX_SPEED * dt).then_some(distance)
+    }
+
+    fn update(&mut self, packet: &CamBall) {
+        match self.track.elapsed(packet.frame_info.t_capture) {
+            Some(dt) => update_ball_track(&mut self.track, packet, dt),
+            None => self.track = new_ball_track(packet),
+        }
+        self.detections = self.detections.saturating_add(1);
+        self.height = packet.position.z;
+    }
+
+    /// A hypothesis is plausible when it has been detected many times and recently.
+    fn plausibility(&self, now: DateTime<Utc>) -> f64 {
+        let age = seconds_between(self.track.timestamp, now);
+        self.detections.min(BALL_MAX_DETECTIONS) as f64
+            * (-age / BALL_OCCLUDED_DELAY.as_secs_f64()).exp()
+    }
+
+    /// Predicts the position and the velocity of the ball `elapsed` seconds after its last
+    /// detection, assuming it rolls freely. A ball predicted inside a robot is stopped against it.
+    fn predict_hidden(
+        &self,
+        elapsed: f64,
+        robots: &[Point2<f64>],
+        radius: f64,
+    ) -> (Point2<f64>, Vector2<f64>) {
+        let velocity = self.velocity();
+        let speed = velocity.norm();
+        let direction = velocity.try_normalize(f64::EPSILON).unwrap_or_default();
+        let rolling_time = (speed / BALL_ROLLING_DECELERATION).min(elapsed);
+        let distance =
+            speed * rolling_time - 0.5 * BALL_ROLLING_DECELERATION * rolling_time.powi(2);
+        let position = self.position() + direction * distance;
+
+        match robots.iter().find(|r| (position - *r).norm() < radius) {
+            Some(robot) => {
+                let away = (position - robot)
+                    .try_normalize(f64::EPSILON)
+                    .unwrap_or(-direction);
+                (robot + away * radius, Vector2::zeros())
+            }
+            None => (
+                position,
+                direction * (speed - BALL_ROLLING_DECELERATION * rolling_time),
+            ),
+        }
+    }
+}
+
+/// The `BallTracker` tracks every object that looks like a ball as a separate hypothesis
+/// to reject the false positives of the vision (reflections, orange clothes, ...), and
+/// selects the most plausible one as the ball.
+///
+/// When the selected ball is not detected anymore, its position is predicted until it is
+/// considered as lost, which takes longer when a robot may hide it.
+#[derive(Default)]
+pub struct BallTracker {
+    hypotheses: Vec<Hypothesis>,
+    selected: Option<u32>,
+    next_id: u32,
+}
+
+impl BallTracker {
+    fn associate(&mut self, packet: &CamBall) {
+        let closest = self
+            .hypotheses
+            .iter_mut()
+            .filter_map(|h| h.gate_distance(packet).map(|d| (h, d)))
+            .min_by(|(_, d1), (_, d2)| d1.total_cmp(d2));
+
+        match closest {
+            Some((hypothesis, _)) => hypothesis.update(packet),
+            None => {
+                self.hypotheses.push(Hypothesis::new(self.next_id, packet));
+                self.next_id = self.next_id.wrapping_add(1);
+            }
+        }
+    }
+
+    fn prune(&mut self, now: DateTime<Utc>) {
+        self.hypotheses.retain(|h| {
+            seconds_between(h.track.timestamp, now) < BALL_HIDDEN_TIMEOUT.as_secs_f64()
+        });
+        if self.hypotheses.len() > BALL_MAX_HYPOTHESES {
+            self.hypotheses
+                .sort_by(|h1, h2| h2.plausibility(now).total_cmp(&h1.plausibility(now)));
+            self.hypotheses.truncate(BALL_MAX_HYPOTHESES);
+        }
+    }
+
+    fn select(&mut self, geometry: &CamGeometry, now: DateTime<Utc>) -> Option<&Hypothesis> {
+        let selected = self.selected;
+        self.selected = self
+            .hypotheses
+            .iter()
+            .filter(|h| h.detections >= BALL_CONFIRMATION_DETECTIONS)
+            .filter(|h| in_field(geometry, &h.position()))
+            .map(|h| {
+                let hysteresis = if selected == Some(h.id) {
+                    BALL_SELECTION_HYSTERESIS
+                } else {
+                    1.0
+                };
+                (h, h.plausibility(now) * hysteresis)
+            })
+            .max_by(|(_, p1), (_, p2)| p1.total_cmp(p2))
+            .map(|(h, _)| h.id);
+
+        self.hypotheses.iter().find(|h| Some(h.id) == self.selected)
+    }
+
+    pub fn step(&mut self, filter_data: &mut FilterData) {
+        let now = filter_data.clock.now();
+        let mut packets = filter_data.ball.packets.drain().collect::<Vec<_>>();
+        packets.sort_by_key(|p| p.frame_info.t_capture);
+        packets.iter().for_each(|packet| self.associate(packet));
+        self.prune(now);
+
+        let robot_radius = filter_data
+            .geometry
+            .max_robot_radius
+            .unwrap_or(DEFAULT_ROBOT_RADIUS);
+        let ball_radius = filter_data
+            .geometry
+            .ball_radius
+            .unwrap_or(DEFAULT_BALL_RADIUS);
+        let robots = filter_data
+            .allies
+            .values()
+            .map(|r| r.data.pose.position)
+            .chain(filter_data.enemies.values().map(|r| r.data.pose.position))
+            .collect::<Vec<_>>();
+
+        let tracked = &mut filter_data.ball;
+        let Some(hypothesis) = self.select(&filter_data.geometry, now) else {
+            tracked.lost = true;
+            return;
+        };
+
+        let elapsed = seconds_between(hypothesis.track.timestamp, now);
+        let (position, velocity, status) = if elapsed < BALL_OCCLUDED_DELAY.as_secs_f64() {
+            (
+                hypothesis.position(),
+                hypothesis.velocity(),
+                BallStatus::Visible,
+            )
+        } else {
+            let (position, velocity) =
+                hypothesis.predict_hidden(elapsed, &robots, robot_radius + ball_radius);
+            let hidden = robots.iter().any(|r| {
+                (position - r).norm() < robot_radius + ball_radius + BALL_OCCLUSION_MARGIN
+            });
+            let timeout = if hidden {
+                BALL_HIDDEN_TIMEOUT
+            } else {
+                BALL_LOST_TIMEOUT
+            };
+            if elapsed > timeout.as_secs_f64() {
+                tracked.lost = true;
+                return;
+            }
+            (position, velocity, BallStatus::Occluded)
+        };
+
+        tracked.lost = false;
+        tracked.data.position = Point3::new(position.x, position.y, hypothesis.height);
+        tracked.data.velocity = Vector3::new(velocity.x, velocity.y, 0.0);
+        tracked.data.timestamp = hypothesis.track.timestamp;
+        tracked.data.status = status;
+    }
+}
@@ -0,0 +1,309 @@
+use nalgebra::{Matrix3, Matrix3x6, Matrix6, Matrix6x3, SMatrix, SVector, Vector3, Vector6};
+use std::f64::consts::PI;
+
+/// A linear Kalman filter using a constant velocity model with `D` dimensions.
+///
+/// The state vector has `S = 2 * D` components: the `D` positions followed by the `D`
+/// velocities. Only the positions are measured.
+#[derive(Clone, Debug)]
+pub struct ConstantVelocityModel<const D: usize, const S: usize> {
+    /// The estimated state, positions followed by velocities.
+    pub state: SVector<f64, S>,
+    /// The covariance of the estimated state.
+    pub covariance: SMatrix<f64, S, S>,
+    /// The variance of the random acceleration applied between two steps.
+    acceleration_noise: SVector<f64, D>,
+    /// The variance of each measured position.
+    measurement_noise: SVector<f64, D>,
+}
+
+impl<const D: usize, const S: usize> ConstantVelocityModel<D, S> {
+    /// Fails the build of any model whose state is not made of the positions and the
+    /// velocities, as stable Rust cannot compute `S` from `D`.
+    const STATE_SIZE: () = assert!(S == 2 * D, "the state must have 2 * D components");
+
+    /// Creates a new model initialized at the given position with an unknown velocity.
+    ///
+    /// # Arguments
+    ///
+    /// * `position`: The first measured position.
+    /// * `acceleration_noise`: The variance of the acceleration on each dimension.
+    /// * `measurement_noise`: The variance of the measurement on each dimension.
+    /// * `velocity_variance`: The variance of the initial velocity estimate.
+    pub fn new(
+        position: SVector<f64, D>,
+        acceleration_noise: SVector<f64, D>,
+        measurement_noise: SVector<f64, D>,
+        velocity_variance: f64,
+    ) -> Self {
+        let () = Self::STATE_SIZE;
+        let mut state = SVector::<f64, S>::zeros();
+        state.fixed_rows_mut::<D>(0).copy_from(&position);
+
+        let mut covariance = SMatrix::<f64, S, S>::zeros();
+        for i in 0..D {
+            covariance[(i, i)] = measurement_noise[i];
+            covariance[(i + D, i + D)] = velocity_variance;
+        }
+
+        Self {
+            state,
+            covariance,
+            acceleration_noise,
+            measurement_noise,
+        }
+    }
+
+    /// Predicts the state `dt` seconds in the future.
+    pub fn predict(&mut self, dt: f64) {
+        let mut transition = SMatrix::<f64, S, S>::identity();
+        let mut process_noise = SMatrix::<f64, S, S>::zeros();
+        for i in 0..D {
+            let noise = self.acceleration_noise[i];
+            transition[(i, i + D)] = dt;
+            process_noise[(i, i)] = dt.powi(4) / 4.0 * noise;
+            process_noise[(i, i + D)] = dt.powi(3) / 2.0 * noise;
+            process_noise[(i + D, i)] = dt.powi(3) / 2.0 * noise;
+            process_noise[(i + D, i + D)] = dt.powi(2) * noise;
+        }
+
+        self.state = transition * self.state;
+        self.covariance = transition * self.covariance * transition.transpose() + process_noise;
+    }
+
+    /// Corrects the state with a measured position.
+    pub fn update(&mut self, measurement: SVector<f64, D>) {
+        let observation = SMatrix::<f64, D, S>::identity();
+        let innovation = measurement - observation * self.state;
+        let innovation_covariance = observation * self.covariance * observation.transpose()
+            + SMatrix::<f64, D, D>::from_diagonal(&self.measurement_noise);
+
+        if let Some(inverse) = innovation_covariance.try_inverse() {
+            let gain = self.covariance * observation.transpose() * inverse;
+            self.state += gain * innovation;
+            self.covariance =
+                (SMatrix::<f64, S, S>::identity() - gain * observation) * self.covariance;
+        }
+    }
+
+    /// Returns the estimated position.
+    pub fn position(&self) -> SVector<f64, D> {
+        self.state.fixed_rows::<D>(0).into_owned()
+    }
+
+    /// Returns the estimated velocity.
+    pub fn velocity(&self) -> SVector<f64, D> {
+        self.state.fixed_rows::<D>(D).into_owned()
+    }
+}
+
+/// Returns the angle wrapped in `[-PI, PI)`.
+fn angle_wrap(alpha: f64) -> f64 {
+    (alpha + PI).rem_euclid(2.0 * PI) - PI
+}
+
+/// An extended Kalman filter tracking the pose and the velocity of an omnidirectional robot.
+///
+/// The state is (x, y, θ, vx, vy, ω), with the linear velocity expressed in the frame of the
+/// robot, in which it stays constant while the robot turns. Only the pose is measured.
+#[derive(Clone, Debug)]
+pub struct RobotModel {
+    /// The estimated state, pose followed by velocities.
+    pub state: Vector6<f64>,
+    /// The covariance of the estimated state.
+    pub covariance: Matrix6<f64>,
+    /// The variance of the random accelerations, in the frame of the robot, applied between
+    /// two steps.
+    acceleration_noise: Vector3<f64>,
+    /// The variance of each measured pose component.
+    measurement_noise: Vector3<f64>,
+}
+
+impl RobotModel {
+    /// Creates a new model initialized at the given pose with an unknown velocity.
+    ///
+    /// # Arguments
+    ///
+    /// * `pose`: The first measured pose.
+    /// * `acceleration_noise`: The variance of the linear and angular accelerations.
+    /// * `measurement_noise`: The variance of the measurement of each pose component.
+    /// * `velocity_variance`: The variance of the initial velocity estimate.
+    pub fn new(
+        pose: Vector3<f64>,
+        acceleration_noise: Vector3<f64>,
+        measurement_noise: Vector3<f64>,
+        velocity_variance: f64,
+    ) -> Self {
+        let mut state = Vector6::zeros();
+        state.fixed_rows_mut::<3>(0).copy_from(&pose);
+        state[2] = angle_wrap(state[2]);
+
+        let mut covariance = Matrix6::zeros();
+        for i in 0..3 {
+            covariance[(i, i)] = measurement_noise[i];
+            covariance[(i + 3, i + 3)] = velocity_variance;
+        }
+
+        Self {
+            state,
+            covariance,
+            acceleration_noise,
+            measurement_noise,
+        }
+    }
+
+    /// Predicts the state `dt` seconds in the future.
+    pub fn predict(&mut self, dt: f64) {
+        let (sin, cos) = self.state[2].sin_cos();
+        let (vx, vy) = (self.state[3], self.state[4]);
+
+        // The jacobian of the motion model at the current state.
+        let mut transition = Matrix6::identity();
+        transition[(0, 2)] = -dt * (vx * sin + vy * cos);
+        transition[(1, 2)] = dt * (vx * cos - vy * sin);
+        transition[(0, 3)] = dt * cos;
+        transition[(0, 4)] = -dt * sin;
+        transition[(1, 3)] = dt * sin;
+        transition[(1, 4)] = dt * cos;
+        transition[(2, 5)] = dt;
+
+        // How the accelerations in the frame of the robot change the state over the step.
+        let mut acceleration = Matrix6x3::zeros();
+        let half_dt2 = dt.powi(2) / 2.0;
+        acceleration[(0, 0)] = half_dt2 * cos;
+        acceleration[(0, 1)] = -half_dt2 * sin;
+        acceleration[(1, 0)] = half_dt2 * sin;
+        acceleration[(1, 1)] = half_dt2 * cos;
+        acceleration[(2, 2)] = half_dt2;
+        acceleration[(3, 0)] = dt;
+        acceleration[(4, 1)] = dt;
+        acceleration[(5, 2)] = dt;
+        let process_noise = acceleration
+            * Matrix3::from_diagonal(&self.acceleration_noise)
+            * acceleration.transpose();
+
+        self.state[0] += dt * (vx * cos - vy * sin);
+        self.state[1] += dt * (vx * sin + vy * cos);
+        self.state[2] = angle_wrap(self.state[2] + dt * self.state[5]);
+        self.covariance = transition * self.covariance * transition.transpose() + process_noise;
+    }
+
+    /// Corrects the state with a measured pose.
+    pub fn update(&mut self, measurement: Vector3<f64>) {
+        let observation = Matrix3x6::identity();
+        let mut innovation = measurement - observation * self.state;
+        // The orientation is measured as close as possible to the estimate, to avoid jumps
+        // around ±π.
+        innovation[2] = angle_wrap(innovation[2]);
+        let innovation_covariance = observation * self.covariance * observation.transpose()
+            + Matrix3::from_diagonal(&self.measurement_noise);
+
+        if let Some(inverse) = innovation_covariance.try_inverse() {
+            let gain = self.covariance * observation.transpose() * inverse;
+            self.state += gain * innovation;
+            self.state[2] = angle_wrap(self.state[2]);
+            self.covariance = (Matrix6::identity() - gain * observation) * self.covariance;
+        }
+    }
+
+    /// Returns the estimated pose.
+    pub fn pose(&self) -> Vector3<f64> {
+        self.state.fixed_rows::<3>(0).into_owned()
+    }
+
+    /// Returns the estimated linear velocity in the frame of the field, and the angular
+    /// velocity.
+    pub fn velocity(&self) -> Vector3<f64> {
+        let (sin, cos) = self.state[2].sin_cos();
+        let (vx, vy) = (self.state[3], self.state[4]);
+        Vector3::new(vx * cos - vy * sin, vx * sin + vy * cos, self.state[5])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::Vector2;
+
+    const DT: f64 = 1.0 / 60.0;
+    const STEPS: usize = 120;
+
+    /// A deterministic measurement noise of about a millimeter.
+    fn noise(step: usize) -> f64 {
+        1e-3 * (step as f64 * 1.7).sin()
+    }
+
+    fn robot_model(pose: Vector3<f64>) -> RobotModel {
+        RobotModel::new(
+            pose,
+            Vector3::new(9.0, 9.0, 25.0),
+            Vector3::new(1e-5, 1e-5, 4e-4),
+            1.0,
+        )
+    }
+
+    #[test]
+    fn ball_converges() {
+        let start = Vector2::new(-1.0, 0.5);
+        let velocity = Vector2::new(1.0, -0.5);
+        let mut model = ConstantVelocityModel::<2, 4>::new(
+            start,
+            Vector2::new(10.0, 10.0),
+            Vector2::new(1e-5, 1e-5),
+            1.0,
+        );
+        for step in 1..=STEPS {
+            model.predict(DT);
+            let position = start + velocity * (step as f64 * DT);
+            model.update(position.add_scalar(noise(step)));
+        }
+
+        let position = start + velocity * (STEPS as f64 * DT);
+        assert!((model.position() - position).norm() < 0.01);
+        assert!((model.velocity() - velocity).norm() < 0.05);
+    }
+
+    #[test]
+    fn robot_converges_on_line() {
+        let start = Vector3::new(0.5, -1.0, 0.8);
+        let velocity = Vector3::new(-0.6, 1.2, 0.0);
+        let mut model = robot_model(start);
+        for step in 1..=STEPS {
+            model.predict(DT);
+            let pose = start + velocity * (step as f64 * DT);
+            model.update(pose.add_scalar(noise(step)));
+        }
+
+        let pose = start + velocity * (STEPS as f64 * DT);
+        assert!((model.pose() - pose).norm() < 0.01);
+        assert!((model.velocity() - velocity).norm() < 0.05);
+    }
+
+    #[test]
+    fn robot_converges_on_circle() {
+        // Moving forward while turning, the robot follows a circle around the origin.
+        let (speed, angular) = (1.0, 2.0);
+        let radius = speed / angular;
+        let pose = |t: f64| {
+            let orientation = 3.0 + angular * t;
+            Vector3::new(
+                radius * orientation.sin(),
+                -radius * orientation.cos(),
+                angle_wrap(orientation),
+            )
+        };
+        let mut model = robot_model(pose(0.0));
+        for step in 1..=STEPS {
+            model.predict(DT);
+            let measured = pose(step as f64 * DT);
+            model.update(measured.add_scalar(noise(step)));
+        }
+
+        let expected = pose(STEPS as f64 * DT);
+        let estimated = model.pose();
+        assert!((estimated.xy() - expected.xy()).norm() < 0.01);
+        assert!(angle_wrap(estimated.z - expected.z).abs() < 0.01);
+        let velocity = Vector3::new(speed * expected.z.cos(), speed * expected.z.sin(), angular);
+        assert!((model.velocity() - velocity).norm() < 0.05);
+    }
+}
@@ -1,6 +1,10 @@
-use crate::data::{FilterData, TrackedBall, TrackedRobot};
+use crate::constant::{
+    BALL_HIDDEN_TIMEOUT, BALL_LOST_TIMEOUT, BALL_OCCLUDED_DELAY, BALL_OCCLUSION_MARGIN,
+    DEFAULT_BALL_RADIUS, DEFAULT_ROBOT_RADIUS,
+};
+use crate::data::{FilterData, TrackedRobot};
 use crate::filter::Filter;
-use crabe_framework::data::world::{Ball, Pose, Robot, World};
+use crabe_framework::data::world::{Ball, BallStatus, Pose, World};
 use ringbuffer::RingBuffer;
 
 fn robot_passthrough<'a, T: 'a + Default>(
@@ -9,20 +13,18 @@ fn robot_passthrough<'a, T: 'a + Default>(
     robots.for_each(|(_id, r)| {
         let last_packet = r.packets.drain().last();
         if let Some(packet) = last_packet {
-            r.data = Robot {
-                id: packet.id,
-                pose: Pose::new(packet.position, packet.orientation),
-                has_ball: false,
-                robot_info: T::default(),
-                velocity: Default::default(),
-                acceleration: Default::default(),
-                timestamp: packet.frame_info.t_capture,
-            }
+            // The possession and the robot info come from the feedback of the robots.
+            r.data.id = packet.id;
+            r.data.pose = Pose::new(packet.position, packet.orientation);
+            r.data.velocity = Default::default();
+            r.data.acceleration = Default::default();
+            r.data.timestamp = packet.frame_info.t_capture;
         }
     })
 }
 
-fn ball_passthrough(ball: &mut TrackedBall) {
+fn ball_passthrough(filter_data: &mut FilterData) {
+    let ball = &mut filter_data.ball;
     let last_packet = ball.packets.drain().last();
     if let Some(packet) = last_packet {
         ball.data = Ball {
@@ -30,7 +32,42 @@ fn ball_passthrough(ball: &mut TrackedBall) {
             timestamp: packet.frame_info.t_capture,
             velocity: Default::default(),
             acceleration: Default::default(),
-        }
+            status: BallStatus::Visible,
+            flight: ball.data.flight.take(),
+            kick: None,
+        };
+        ball.lost = false;
+        return;
+    }
+    if ball.lost {
+        return;
+    }
+
+    // As with the ball tracker, the ball is lost after some time without detection, longer
+    // when a robot may hide it.
+    let geometry = &filter_data.geometry;
+    let reach = geometry.max_robot_radius.unwrap_or(DEFAULT_ROBOT_RADIUS)
+        + geometry.ball_radius.unwrap_or(DEFAULT_BALL_RADIUS)
+        + BALL_OCCLUSION_MARGIN;
+    let position = ball.data.position_2d();
+    let hidden = filter_data
+        .allies
+        .values()
+        .map(|r| r.data.pose.position)
+        .chain(filter_data.enemies.values().map(|r| r.data.pose.position))
+        .any(|robot| (position - robot).norm() < reach);
+    let timeout = if hidden {
+        BALL_HIDDEN_TIMEOUT
+    } else {
+        BALL_LOST_TIMEOUT
+    };
+    let elapsed = (filter_data.clock.now() - ball.data.timestamp)
+        .to_std()
+        .unwrap_or_default();
+    if elapsed > timeout {
+        ball.lost = true;
+    } else if elapsed > BALL_OCCLUDED_DELAY {
+        ball.data.status = BallStatus::Occluded;
     }
 }
 
@@ -40,6 +77,6 @@ impl Filter for PassthroughFilter {
     fn step(&mut self, filter_data: &mut FilterData, _world: &World) {
         robot_passthrough(filter_data.allies.iter_mut());
         robot_passthrough(filter_data.enemies.iter_mut());
-        ball_passthrough(&mut filter_data.ball);
+        ball_passthrough(filter_data);
     }
 }
@@ -1,3 +1,4 @@
+mod clock;
 mod constant;
 mod data;
 mod filter;
@@ -6,23 +7,43 @@ mod pre_filter;
 
 use crate::data::FilterData;
 
+use crate::filter::chip::ChipFilter;
+use crate::filter::fusion::CameraFusionFilter;
 use crate::filter::inactive::InactiveFilter;
+use crate::filter::kalman::KalmanFilter;
 use crate::filter::passthrough::PassthroughFilter;
 use crate::filter::Filter;
 use crate::post_filter::ball::BallFilter;
+use crate::post_filter::game_data::GameDataFilter;
 use crate::post_filter::geometry::GeometryFilter;
+use crate::post_filter::normalize::NormalizeFilter;
+use crate::post_filter::possession::PossessionFilter;
 use crate::post_filter::robot::RobotFilter;
 use crate::post_filter::PostFilter;
+use crate::pre_filter::feedback::FeedbackFilter;
+use crate::pre_filter::game_controller::GameControllerFilter;
+use crate::pre_filter::tracker::TrackerFilter;
 use crate::pre_filter::vision::VisionFilter;
 use crate::pre_filter::PreFilter;
 use clap::Args;
 use crabe_framework::component::{Component, FilterComponent};
 use crabe_framework::config::CommonConfig;
 use crabe_framework::data::input::InboundData;
-use crabe_framework::data::world::{TeamColor, World};
+use crabe_framework::data::world::{GameData, TeamColor, World};
 
 #[derive(Args)]
-pub struct FilterConfig {}
+pub struct FilterConfig {
+    /// Rotate the world so that our team always attacks towards the positive x axis.
+    #[arg(long)]
+    pub normalize_field: bool,
+    /// Use the last detection of each object instead of the Kalman filter.
+    #[arg(long)]
+    pub passthrough: bool,
+    /// Fill the world from the tracked frames of an external tracker instead of our own
+    /// filters (implies --tracker).
+    #[arg(long)]
+    pub use_tracker: bool,
+}
 
 pub struct FilterPipeline {
     pub pre_filters: Vec<Box<dyn PreFilter>>,
@@ -33,29 +54,56 @@ pub struct FilterPipeline {
 }
 
 impl FilterPipeline {
-    pub fn with_config(_config: FilterConfig, common_config: &CommonConfig) -> Self {
+    pub fn with_config(config: FilterConfig, common_config: &CommonConfig) -> Self {
+        let team_color = if common_config.yellow {
+            TeamColor::Yellow
+        } else {
+            TeamColor::Blue
+        };
+
+        let mut post_filters: Vec<Box<dyn PostFilter>> = vec![
+            Box::new(RobotFilter),
+            Box::new(GeometryFilter),
+            Box::new(BallFilter),
+            Box::new(GameDataFilter),
+            Box::<PossessionFilter>::default(),
+        ];
+        if config.normalize_field {
+            post_filters.push(Box::new(NormalizeFilter));
+        }
+
+        let mut pre_filters: Vec<Box<dyn PreFilter>> = vec![];
+        let mut filters: Vec<Box<dyn Filter>> = vec![];
+        if config.use_tracker {
+            pre_filters.push(Box::new(VisionFilter::geometry_only()));
+            pre_filters.push(Box::new(TrackerFilter::new()));
+        } else {
+            pre_filters.push(Box::new(VisionFilter::new()));
+            filters.push(Box::<CameraFusionFilter>::default());
+            filters.push(Box::<ChipFilter>::default());
+            if config.passthrough {
+                filters.push(Box::new(PassthroughFilter));
+            } else {
+                filters.push(Box::<KalmanFilter>::default());
+            }
+        }
+        pre_filters.push(Box::new(GameControllerFilter::new()));
+        pre_filters.push(Box::new(FeedbackFilter));
+        filters.push(Box::<InactiveFilter>::default());
+
         Self {
-            pre_filters: vec![Box::new(VisionFilter::new())],
-            filters: vec![
-                Box::new(PassthroughFilter),
-                Box::<InactiveFilter>::default(),
-            ],
-            post_filters: vec![
-                Box::new(RobotFilter),
-                Box::new(GeometryFilter),
-                Box::new(BallFilter),
-            ],
+            pre_filters,
+            filters,
+            post_filters,
             filter_data: FilterData {
                 allies: Default::default(),
                 enemies: Default::default(),
                 ball: Default::default(),
                 geometry: Default::default(),
+                game_data: GameData::new(team_color),
+                clock: Default::default(),
             },
-            team_color: if common_config.yellow {
-                TeamColor::Yellow
-            } else {
-                TeamColor::Blue
-            },
+            team_color,
         }
     }
 }
@@ -1,5 +1,8 @@
 pub mod ball;
+pub mod game_data;
 pub mod geometry;
+pub mod normalize;
+pub mod possession;
 pub mod robot;
 
 use crate::data::FilterData;
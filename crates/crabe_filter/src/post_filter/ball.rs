@@ -6,6 +6,6 @@ pub struct BallFilter;
 
 impl PostFilter for BallFilter {
     fn step(&mut self, filter_data: &FilterData, world: &mut World) {
-        world.ball = Some(filter_data.ball.data.clone());
+        world.ball = (!filter_data.ball.lost).then(|| filter_data.ball.data.clone());
     }
 }
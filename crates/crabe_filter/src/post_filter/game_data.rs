@@ -0,0 +1,11 @@
+use crate::data::FilterData;
+use crate::post_filter::PostFilter;
+use crabe_framework::data::world::World;
+
+pub struct GameDataFilter;
+
+impl PostFilter for GameDataFilter {
+    fn step(&mut self, filter_data: &FilterData, world: &mut World) {
+        world.data = filter_data.game_data.clone();
+    }
+}
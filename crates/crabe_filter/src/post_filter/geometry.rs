@@ -68,6 +68,7 @@ fn geometry_to_goal(cam_geometry: &CamGeometry, positive: bool) -> Goal {
 impl PostFilter for GeometryFilter {
     fn step(&mut self, filter_data: &FilterData, world: &mut World) {
         let cam_geometry = &filter_data.geometry;
+        let ally_positive = filter_data.game_data.ally_on_positive_half();
 
         let geometry = Geometry {
             boundary_width: cam_geometry.boundary_width,
@@ -75,10 +76,10 @@ impl PostFilter for GeometryFilter {
                 width: cam_geometry.field_width,
                 length: cam_geometry.field_length,
             },
-            ally_goal: geometry_to_goal(cam_geometry, false),
-            enemy_goal: geometry_to_goal(cam_geometry, true),
-            ally_penalty: geometry_to_penalty(cam_geometry, false),
-            enemy_penalty: geometry_to_penalty(cam_geometry, true),
+            ally_goal: geometry_to_goal(cam_geometry, ally_positive),
+            enemy_goal: geometry_to_goal(cam_geometry, !ally_positive),
+            ally_penalty: geometry_to_penalty(cam_geometry, ally_positive),
+            enemy_penalty: geometry_to_penalty(cam_geometry, !ally_positive),
             center: geometry_to_center(cam_geometry),
         };
 
@@ -0,0 +1,63 @@
+use crate::data::FilterData;
+use crate::post_filter::PostFilter;
+use crabe_framework::data::world::{Robot, World};
+use nalgebra::{Point2, Vector3};
+use std::f64::consts::PI;
+
+/// The `NormalizeFilter` rotates the whole world by half a turn around the center of the field
+/// when our team defends the positive half, so that strategies can always consider that
+/// we attack towards the positive x axis.
+///
+/// A rotation is used instead of a mirror to keep the robot local frames (and therefore the
+/// commands sent to the robots) valid.
+pub struct NormalizeFilter;
+
+fn rotate_point(point: &mut Point2<f64>) {
+    *point = Point2::from(-point.coords);
+}
+
+fn rotate_robot<T>(robot: &mut Robot<T>) {
+    rotate_point(&mut robot.pose.position);
+    // Add half a turn and wrap the result in [-π, π)
+    robot.pose.orientation = (robot.pose.orientation + 2.0 * PI) % (2.0 * PI) - PI;
+    robot.velocity.linear = -robot.velocity.linear;
+    robot.acceleration.linear = -robot.acceleration.linear;
+}
+
+impl PostFilter for NormalizeFilter {
+    fn step(&mut self, _filter_data: &FilterData, world: &mut World) {
+        if !world.data.ally_on_positive_half() {
+            return;
+        }
+
+        world.allies_bot.values_mut().for_each(rotate_robot);
+        world.enemies_bot.values_mut().for_each(rotate_robot);
+
+        if let Some(ball) = world.ball.as_mut() {
+            let flip = Vector3::new(-1.0, -1.0, 1.0);
+            ball.position.coords.component_mul_assign(&flip);
+            ball.velocity.component_mul_assign(&flip);
+            ball.acceleration.component_mul_assign(&flip);
+            if let Some(flight) = ball.flight.as_mut() {
+                rotate_point(&mut flight.landing_position);
+            }
+            if let Some(kick) = ball.kick.as_mut() {
+                rotate_point(&mut kick.position);
+                kick.velocity.component_mul_assign(&flip);
+                if let Some(position) = kick.stop_position.as_mut() {
+                    rotate_point(position);
+                }
+            }
+        }
+
+        let geometry = &mut world.geometry;
+        std::mem::swap(&mut geometry.ally_goal, &mut geometry.enemy_goal);
+        std::mem::swap(&mut geometry.ally_penalty, &mut geometry.enemy_penalty);
+        rotate_point(&mut geometry.center.center);
+
+        if let Some(position) = world.data.designated_position.as_mut() {
+            rotate_point(position);
+        }
+        world.data.positive_half = world.data.enemy.color;
+    }
+}
@@ -0,0 +1,120 @@
+use crate::constant::{
+    DEFAULT_BALL_RADIUS, DEFAULT_ROBOT_RADIUS, FEEDBACK_TIMEOUT, POSSESSION_DISTANCE,
+    POSSESSION_RELEASE_DISTANCE,
+};
+use crate::data::FilterData;
+use crate::post_filter::PostFilter;
+use crabe_framework::data::world::{BallControl, Robot, RobotMap, World};
+use nalgebra::{Point2, Vector2};
+use std::collections::HashSet;
+
+/// Returns the distance between the ball and the dribbler of the robot, which is in front of
+/// the robot at `offset` meters from its center.
+fn dribbler_distance<T>(robot: &Robot<T>, ball: &Point2<f64>, offset: f64) -> f64 {
+    let orientation = robot.pose.orientation;
+    let dribbler =
+        robot.pose.position + Vector2::new(orientation.cos(), orientation.sin()) * offset;
+    (ball - dribbler).norm()
+}
+
+/// Updates the possession of the robots from the position of the ball and returns the robot
+/// holding the ball the closest to its dribbler.
+///
+/// A robot holding the ball keeps it until the ball goes further than the release distance.
+/// The robots for which `reported` returns true keep the possession they reported.
+fn update_robots<T>(
+    robots: &mut RobotMap<T>,
+    holders: &mut HashSet<u8>,
+    ball: Option<Point2<f64>>,
+    offset: f64,
+    reported: impl Fn(&Robot<T>) -> bool,
+) -> Option<u8> {
+    let mut closest: Option<(u8, f64)> = None;
+    for robot in robots.values_mut() {
+        let distance = ball.map_or(f64::INFINITY, |ball| {
+            dribbler_distance(robot, &ball, offset)
+        });
+        if !reported(robot) {
+            let limit = if holders.contains(&robot.id) {
+                POSSESSION_RELEASE_DISTANCE
+            } else {
+                POSSESSION_DISTANCE
+            };
+            robot.has_ball = distance < limit;
+        }
+
+        if robot.has_ball {
+            holders.insert(robot.id);
+            if !closest.is_some_and(|(_, d)| d <= distance) {
+                closest = Some((robot.id, distance));
+            }
+        } else {
+            holders.remove(&robot.id);
+        }
+    }
+
+    holders.retain(|id| robots.contains_key(id));
+    closest.map(|(id, _)| id)
+}
+
+/// The `PossessionFilter` infers which robots hold the ball from the position of the ball
+/// relative to their dribbler, and summarizes which team controls the ball.
+///
+/// The ally robots sending feedback keep the possession they report.
+#[derive(Default)]
+pub struct PossessionFilter {
+    allies: HashSet<u8>,
+    enemies: HashSet<u8>,
+}
+
+impl PostFilter for PossessionFilter {
+    fn step(&mut self, filter_data: &FilterData, world: &mut World) {
+        let now = filter_data.clock.now();
+        let geometry = &filter_data.geometry;
+        let offset = geometry.max_robot_radius.unwrap_or(DEFAULT_ROBOT_RADIUS)
+            + geometry.ball_radius.unwrap_or(DEFAULT_BALL_RADIUS);
+        // A ball in the air cannot be held.
+        let ball = world
+            .ball
+            .as_ref()
+            .filter(|ball| !ball.is_airborne())
+            .map(|ball| ball.position_2d());
+
+        let ally = update_robots(
+            &mut world.allies_bot,
+            &mut self.allies,
+            ball,
+            offset,
+            |robot| {
+                robot.robot_info.last_feedback.is_some_and(|last| {
+                    (now - last)
+                        .to_std()
+                        .is_ok_and(|elapsed| elapsed <= FEEDBACK_TIMEOUT)
+                })
+            },
+        );
+        let enemy = update_robots(
+            &mut world.enemies_bot,
+            &mut self.enemies,
+            ball,
+            offset,
+            |_| false,
+        );
+
+        let control = match (ally, enemy) {
+            (Some(_), Some(_)) => BallControl::Contested,
+            (Some(id), None) => BallControl::Ally(id),
+            (None, Some(id)) => BallControl::Enemy(id),
+            (None, None) => BallControl::Free,
+        };
+
+        let possession = &mut world.possession;
+        if control != possession.control {
+            possession.control = control;
+            possession.since = now;
+        }
+        possession.duration = (now - possession.since)
+            .to_std()
+            .map_or(0.0, |d| d.as_secs_f64());
+    }
+}
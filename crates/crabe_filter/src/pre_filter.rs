@@ -2,6 +2,9 @@ use crate::data::FilterData;
 use crabe_framework::data::input::InboundData;
 use crabe_framework::data::world::TeamColor;
 
+pub mod feedback;
+pub mod game_controller;
+pub mod tracker;
 pub mod vision;
 
 pub trait PreFilter {
@@ -0,0 +1,49 @@
+use crate::constant::FEEDBACK_TIMEOUT;
+use crate::data::FilterData;
+use crate::pre_filter::PreFilter;
+use crabe_framework::data::input::InboundData;
+use crabe_framework::data::world::TeamColor;
+
+/// The `FeedbackFilter` updates the ally robots with the information they report
+/// (possession of the ball, battery, kicker), and discards it when a robot stops reporting.
+pub struct FeedbackFilter;
+
+impl PreFilter for FeedbackFilter {
+    fn step(
+        &mut self,
+        inbound_data: &InboundData,
+        _team_color: &TeamColor,
+        filter_data: &mut FilterData,
+    ) {
+        let now = filter_data.clock.now();
+        for (id, feedback) in inbound_data.feedback.iter() {
+            let Some(robot) = u8::try_from(*id)
+                .ok()
+                .and_then(|id| filter_data.allies.get_mut(&id))
+            else {
+                continue;
+            };
+
+            let data = &mut robot.data;
+            data.has_ball = feedback.has_ball;
+            // The simulators do not report any voltage.
+            data.robot_info.battery_voltage = (feedback.voltage > 0.0).then_some(feedback.voltage);
+            data.robot_info.kicker_charged = feedback.kicker_charged;
+            data.robot_info.last_feedback = Some(now);
+        }
+
+        filter_data.allies.values_mut().for_each(|robot| {
+            let info = &mut robot.data.robot_info;
+            let expired = info.last_feedback.is_some_and(|last| {
+                (now - last)
+                    .to_std()
+                    .is_ok_and(|elapsed| elapsed > FEEDBACK_TIMEOUT)
+            });
+            if expired {
+                robot.data.has_ball = false;
+                info.battery_voltage = None;
+                info.kicker_charged = None;
+            }
+        });
+    }
+}
@@ -0,0 +1,312 @@
+use crate::constant::BALL_MOVED_DISTANCE;
+use crate::data::FilterData;
+use crate::pre_filter::PreFilter;
+use crabe_framework::data::input::InboundData;
+use crabe_framework::data::world::{GameState, Restart, Stage, Team, TeamColor};
+use crabe_protocol::protobuf::game_controller_packet::referee::{Command, TeamInfo};
+use crabe_protocol::protobuf::game_controller_packet::{referee, Referee};
+use log::info;
+use nalgebra::Point2;
+
+/// Returns the next state of the match when the referee issues the given command.
+fn next_state(command: Command, state: GameState) -> GameState {
+    match command {
+        Command::Halt | Command::TimeoutYellow | Command::TimeoutBlue => GameState::Halt,
+        // Goal commands are deprecated, the game controller sends a STOP right after.
+        Command::Stop | Command::GoalYellow | Command::GoalBlue => GameState::Stop,
+        Command::NormalStart => match state {
+            GameState::PrepareKickoff(team) => GameState::NormalStart(Restart::Kickoff(team)),
+            GameState::PreparePenalty(team) => GameState::NormalStart(Restart::Penalty(team)),
+            _ => GameState::Running,
+        },
+        Command::ForceStart => GameState::ForceStart,
+        Command::PrepareKickoffYellow => GameState::PrepareKickoff(TeamColor::Yellow),
+        Command::PrepareKickoffBlue => GameState::PrepareKickoff(TeamColor::Blue),
+        Command::PreparePenaltyYellow => GameState::PreparePenalty(TeamColor::Yellow),
+        Command::PreparePenaltyBlue => GameState::PreparePenalty(TeamColor::Blue),
+        Command::DirectFreeYellow | Command::IndirectFreeYellow => {
+            GameState::FreeKick(TeamColor::Yellow)
+        }
+        Command::DirectFreeBlue | Command::IndirectFreeBlue => GameState::FreeKick(TeamColor::Blue),
+        Command::BallPlacementYellow => GameState::BallPlacement(TeamColor::Yellow),
+        Command::BallPlacementBlue => GameState::BallPlacement(TeamColor::Blue),
+    }
+}
+
+/// Returns whether the game starts running once the ball has been kicked in the given state.
+fn waits_for_kick(state: GameState) -> bool {
+    matches!(
+        state,
+        GameState::NormalStart(_) | GameState::FreeKick(_) | GameState::ForceStart
+    )
+}
+
+fn stage_from_referee(stage: referee::Stage) -> Stage {
+    match stage {
+        referee::Stage::NormalFirstHalfPre => Stage::NormalFirstHalfPre,
+        referee::Stage::NormalFirstHalf => Stage::NormalFirstHalf,
+        referee::Stage::NormalHalfTime => Stage::NormalHalfTime,
+        referee::Stage::NormalSecondHalfPre => Stage::NormalSecondHalfPre,
+        referee::Stage::NormalSecondHalf => Stage::NormalSecondHalf,
+        referee::Stage::ExtraTimeBreak => Stage::ExtraTimeBreak,
+        referee::Stage::ExtraFirstHalfPre => Stage::ExtraFirstHalfPre,
+        referee::Stage::ExtraFirstHalf => Stage::ExtraFirstHalf,
+        referee::Stage::ExtraHalfTime => Stage::ExtraHalfTime,
+        referee::Stage::ExtraSecondHalfPre => Stage::ExtraSecondHalfPre,
+        referee::Stage::ExtraSecondHalf => Stage::ExtraSecondHalf,
+        referee::Stage::PenaltyShootoutBreak => Stage::PenaltyShootoutBreak,
+        referee::Stage::PenaltyShootout => Stage::PenaltyShootout,
+        referee::Stage::PostGame => Stage::PostGame,
+    }
+}
+
+fn update_team(team: &mut Team, info: &TeamInfo) {
+    team.name = Some(info.name.clone());
+    team.score = info.score;
+    team.yellow_cards = info.yellow_cards;
+    team.goalkeeper = info.goalkeeper as u8;
+    team.timeouts = info.timeouts;
+}
+
+/// The `GameControllerFilter` decodes the packets sent by the game controller and keeps track
+/// of the state of the match.
+#[derive(Default)]
+pub struct GameControllerFilter {
+    /// The counter of the last handled command, used to detect new commands.
+    command_counter: Option<u32>,
+    /// The position of the ball when a restart was allowed, used to detect the kick.
+    kick_position: Option<Point2<f64>>,
+}
+
+impl GameControllerFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn handle_referee(
+        &mut self,
+        referee: &Referee,
+        team_color: &TeamColor,
+        filter_data: &mut FilterData,
+    ) {
+        let game_data = &mut filter_data.game_data;
+        game_data.stage = stage_from_referee(referee.stage());
+        if let Some(blue_on_positive) = referee.blue_team_on_positive_half {
+            let positive_half = if blue_on_positive {
+                TeamColor::Blue
+            } else {
+                TeamColor::Yellow
+            };
+            if positive_half != game_data.positive_half {
+                info!("Team {:?} is now on the positive half", positive_half);
+            }
+            game_data.positive_half = positive_half;
+        }
+
+        game_data.designated_position = referee
+            .designated_position
+            .as_ref()
+            .map(|p| Point2::new(p.x as f64 / 1000.0, p.y as f64 / 1000.0));
+
+        let (ally_info, enemy_info) = match team_color {
+            TeamColor::Yellow => (&referee.yellow, &referee.blue),
+            TeamColor::Blue => (&referee.blue, &referee.yellow),
+        };
+        update_team(&mut game_data.ally, ally_info);
+        update_team(&mut game_data.enemy, enemy_info);
+
+        if self.command_counter != Some(referee.command_counter) {
+            self.command_counter = Some(referee.command_counter);
+            let state = next_state(referee.command(), game_data.state);
+            if state != game_data.state {
+                info!(
+                    "Game state changed from {:?} to {:?}",
+                    game_data.state, state
+                );
+            }
+            game_data.state = state;
+            self.kick_position = Some(filter_data.ball.data.position_2d());
+        }
+    }
+
+    /// The game runs as soon as the ball has been moved after a restart.
+    fn detect_kick(&mut self, filter_data: &mut FilterData) {
+        let game_data = &mut filter_data.game_data;
+        if !waits_for_kick(game_data.state) {
+            return;
+        }
+
+        let ball_position = filter_data.ball.data.position_2d();
+        let kick_position = *self.kick_position.get_or_insert(ball_position);
+        if (ball_position - kick_position).norm() > BALL_MOVED_DISTANCE {
+            info!(
+                "Ball kicked, game state changed to {:?}",
+                GameState::Running
+            );
+            game_data.state = GameState::Running;
+        }
+    }
+}
+
+impl PreFilter for GameControllerFilter {
+    fn step(
+        &mut self,
+        inbound_data: &InboundData,
+        team_color: &TeamColor,
+        filter_data: &mut FilterData,
+    ) {
+        inbound_data
+            .gc_packet
+            .iter()
+            .for_each(|referee| self.handle_referee(referee, team_color, filter_data));
+
+        self.detect_kick(filter_data);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crabe_framework::data::world::GameData;
+    use nalgebra::Point3;
+
+    /// A game controller filter fed with referee commands and ball positions.
+    struct Match {
+        filter: GameControllerFilter,
+        data: FilterData,
+        counter: u32,
+    }
+
+    impl Match {
+        fn new() -> Self {
+            Self {
+                filter: GameControllerFilter::new(),
+                data: FilterData {
+                    allies: Default::default(),
+                    enemies: Default::default(),
+                    ball: Default::default(),
+                    geometry: Default::default(),
+                    game_data: GameData::new(TeamColor::Blue),
+                    clock: Default::default(),
+                },
+                counter: 0,
+            }
+        }
+
+        fn referee(&self, command: Command) -> Referee {
+            let mut referee = Referee {
+                command_counter: self.counter,
+                ..Default::default()
+            };
+            referee.set_command(command);
+            referee
+        }
+
+        /// Sends a new command and returns the resulting state.
+        fn command(&mut self, command: Command) -> GameState {
+            self.counter += 1;
+            let referee = self.referee(command);
+            self.step(referee)
+        }
+
+        fn step(&mut self, referee: Referee) -> GameState {
+            let inbound_data = InboundData {
+                gc_packet: vec![referee],
+                ..Default::default()
+            };
+            self.filter
+                .step(&inbound_data, &TeamColor::Blue, &mut self.data);
+            self.data.game_data.state
+        }
+
+        /// Moves the ball to the given position and returns the resulting state.
+        fn move_ball(&mut self, x: f64, y: f64) -> GameState {
+            self.data.ball.data.position = Point3::new(x, y, 0.0);
+            self.filter
+                .step(&InboundData::default(), &TeamColor::Blue, &mut self.data);
+            self.data.game_data.state
+        }
+    }
+
+    #[test]
+    fn kickoff() {
+        let mut game = Match::new();
+        assert_eq!(game.command(Command::Stop), GameState::Stop);
+        assert_eq!(
+            game.command(Command::PrepareKickoffBlue),
+            GameState::PrepareKickoff(TeamColor::Blue)
+        );
+        let start = GameState::NormalStart(Restart::Kickoff(TeamColor::Blue));
+        assert_eq!(game.command(Command::NormalStart), start);
+        assert_eq!(game.move_ball(0.01, 0.0), start);
+        assert_eq!(game.move_ball(0.1, 0.0), GameState::Running);
+
+        // The last command is sent again until the next one, and must not restart the game.
+        let referee = game.referee(Command::NormalStart);
+        assert_eq!(game.step(referee), GameState::Running);
+    }
+
+    #[test]
+    fn penalty() {
+        let mut game = Match::new();
+        game.command(Command::Stop);
+        assert_eq!(
+            game.command(Command::PreparePenaltyYellow),
+            GameState::PreparePenalty(TeamColor::Yellow)
+        );
+        assert_eq!(
+            game.command(Command::NormalStart),
+            GameState::NormalStart(Restart::Penalty(TeamColor::Yellow))
+        );
+    }
+
+    #[test]
+    fn free_kick() {
+        let mut game = Match::new();
+        game.move_ball(1.0, -1.0);
+        game.command(Command::Stop);
+        let free_kick = GameState::FreeKick(TeamColor::Yellow);
+        assert_eq!(game.command(Command::DirectFreeYellow), free_kick);
+        assert_eq!(game.move_ball(1.0, -1.02), free_kick);
+        assert_eq!(game.move_ball(1.2, -1.0), GameState::Running);
+        assert_eq!(
+            game.command(Command::IndirectFreeBlue),
+            GameState::FreeKick(TeamColor::Blue)
+        );
+    }
+
+    #[test]
+    fn timeouts() {
+        let mut game = Match::new();
+        game.command(Command::Stop);
+        assert_eq!(game.command(Command::TimeoutYellow), GameState::Halt);
+        game.command(Command::Stop);
+        assert_eq!(game.command(Command::TimeoutBlue), GameState::Halt);
+        assert_eq!(game.move_ball(1.0, 0.0), GameState::Halt);
+    }
+
+    #[test]
+    fn ball_placement() {
+        let mut game = Match::new();
+        game.command(Command::Stop);
+        game.counter += 1;
+        let mut referee = game.referee(Command::BallPlacementBlue);
+        referee.designated_position = Some(referee::Point {
+            x: 1500.0,
+            y: -500.0,
+        });
+        assert_eq!(
+            game.step(referee),
+            GameState::BallPlacement(TeamColor::Blue)
+        );
+        assert_eq!(
+            game.data.game_data.designated_position,
+            Some(Point2::new(1.5, -0.5))
+        );
+        // The ball moving during the placement does not start the game.
+        assert_eq!(
+            game.move_ball(1.0, 0.0),
+            GameState::BallPlacement(TeamColor::Blue)
+        );
+    }
+}
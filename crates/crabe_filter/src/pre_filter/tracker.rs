@@ -0,0 +1,179 @@
+use crate::constant::{GRAVITY, TRACKER_MIN_VISIBILITY};
+use crate::data::{FilterData, TrackedBall, TrackedRobot, TrackedRobotMap};
+use crate::pre_filter::PreFilter;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use crabe_framework::constant::MAX_ID_ROBOTS;
+use crabe_framework::data::input::InboundData;
+use crabe_framework::data::world::{BallFlight, BallKick, BallStatus, Pose, Robot, TeamColor};
+use crabe_protocol::protobuf::tracked_packet;
+use crabe_protocol::protobuf::tracked_packet::{KickedBall, TrackedFrame, TrackerWrapperPacket};
+use log::{info, warn};
+use nalgebra::{Point2, Point3, Vector2, Vector3};
+
+fn date_time(timestamp: f64) -> DateTime<Utc> {
+    Utc.timestamp_nanos((timestamp * 1e9) as i64)
+}
+
+fn from_tracked_color(color: tracked_packet::TeamColor) -> Option<TeamColor> {
+    match color {
+        tracked_packet::TeamColor::Yellow => Some(TeamColor::Yellow),
+        tracked_packet::TeamColor::Blue => Some(TeamColor::Blue),
+        tracked_packet::TeamColor::Unknown => None,
+    }
+}
+
+fn update_robot<T: Default>(
+    robots: &mut TrackedRobotMap<T>,
+    robot: &tracked_packet::TrackedRobot,
+    timestamp: DateTime<Utc>,
+    now: DateTime<Utc>,
+) {
+    if robot.robot_id.id > MAX_ID_ROBOTS as u32 {
+        warn!("invalid id");
+        return;
+    }
+
+    let id = robot.robot_id.id as u8;
+    let tracked = robots.entry(id).or_insert_with(|| TrackedRobot {
+        data: Robot {
+            id,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    tracked.data.pose = Pose::new(
+        Point2::new(robot.pos.x as f64, robot.pos.y as f64),
+        robot.orientation as f64,
+    );
+    tracked.data.velocity.linear = robot
+        .vel
+        .as_ref()
+        .map_or(Vector2::zeros(), |v| Vector2::new(v.x as f64, v.y as f64));
+    tracked.data.velocity.angular = robot.vel_angular.unwrap_or(0.0) as f64;
+    tracked.data.timestamp = timestamp;
+    tracked.last_update = now;
+}
+
+fn kick(kicked_ball: &KickedBall) -> BallKick {
+    BallKick {
+        position: Point2::new(kicked_ball.pos.x as f64, kicked_ball.pos.y as f64),
+        velocity: Vector3::new(
+            kicked_ball.vel.x as f64,
+            kicked_ball.vel.y as f64,
+            kicked_ball.vel.z as f64,
+        ),
+        start_time: date_time(kicked_ball.start_timestamp),
+        stop_time: kicked_ball.stop_timestamp.map(date_time),
+        stop_position: kicked_ball
+            .stop_pos
+            .as_ref()
+            .map(|p| Point2::new(p.x as f64, p.y as f64)),
+        kicker: kicked_ball
+            .robot_id
+            .as_ref()
+            .and_then(|r| from_tracked_color(r.team_color()).map(|color| (color, r.id as u8))),
+    }
+}
+
+/// Returns the flight of a chipped ball that did not land yet at the given time.
+fn flight(kick: &BallKick, timestamp: DateTime<Utc>) -> Option<BallFlight> {
+    if kick.velocity.z <= 0.0 {
+        return None;
+    }
+
+    let duration = 2.0 * kick.velocity.z / GRAVITY;
+    let landing_time = kick.start_time + Duration::microseconds((duration * 1e6) as i64);
+    (landing_time > timestamp).then(|| BallFlight {
+        landing_position: kick.position + kick.velocity.xy() * duration,
+        landing_time,
+        max_height: kick.velocity.z.powi(2) / (2.0 * GRAVITY),
+    })
+}
+
+fn update_ball(tracked: &mut TrackedBall, frame: &TrackedFrame, timestamp: DateTime<Utc>) {
+    // The first ball is the primary one.
+    let Some(ball) = frame.balls.first() else {
+        tracked.lost = true;
+        return;
+    };
+
+    let data = &mut tracked.data;
+    data.position = Point3::new(ball.pos.x as f64, ball.pos.y as f64, ball.pos.z as f64);
+    data.velocity = ball.vel.as_ref().map_or(Vector3::zeros(), |v| {
+        Vector3::new(v.x as f64, v.y as f64, v.z as f64)
+    });
+    data.timestamp = timestamp;
+    data.status = match ball.visibility {
+        Some(visibility) if visibility < TRACKER_MIN_VISIBILITY => BallStatus::Occluded,
+        _ => BallStatus::Visible,
+    };
+    data.kick = frame.kicked_ball.as_ref().map(kick);
+    data.flight = data.kick.as_ref().and_then(|k| flight(k, timestamp));
+    tracked.lost = false;
+}
+
+/// The `TrackerFilter` fills the robots and the ball from the tracked frames of an external
+/// tracker, such as the one of the autoRefs, instead of the detections of the vision.
+///
+/// Only the first source of tracked frames is followed when several trackers broadcast.
+#[derive(Default)]
+pub struct TrackerFilter {
+    /// The UUID of the followed source.
+    source: Option<String>,
+}
+
+impl TrackerFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn handle_frame(
+        &mut self,
+        packet: &TrackerWrapperPacket,
+        team_color: &TeamColor,
+        filter_data: &mut FilterData,
+    ) {
+        let source = self.source.get_or_insert_with(|| {
+            info!(
+                "Following the tracker {} ({})",
+                packet.source_name.as_deref().unwrap_or("unknown"),
+                packet.uuid
+            );
+            packet.uuid.clone()
+        });
+        if *source != packet.uuid {
+            return;
+        }
+        let Some(frame) = packet.tracked_frame.as_ref() else {
+            return;
+        };
+
+        let timestamp = date_time(frame.timestamp);
+        let now = filter_data.clock.now();
+        for robot in &frame.robots {
+            match from_tracked_color(robot.robot_id.team_color()) {
+                Some(color) if color == *team_color => {
+                    update_robot(&mut filter_data.allies, robot, timestamp, now)
+                }
+                Some(_) => update_robot(&mut filter_data.enemies, robot, timestamp, now),
+                None => warn!("tracked robot without team color"),
+            }
+        }
+
+        update_ball(&mut filter_data.ball, frame, timestamp);
+    }
+}
+
+impl PreFilter for TrackerFilter {
+    fn step(
+        &mut self,
+        inbound_data: &InboundData,
+        team_color: &TeamColor,
+        filter_data: &mut FilterData,
+    ) {
+        inbound_data
+            .tracker_packet
+            .iter()
+            .for_each(|packet| self.handle_frame(packet, team_color, filter_data));
+    }
+}
@@ -6,10 +6,8 @@ use crabe_framework::data::world::TeamColor;
 
 mod detection {
     use crate::data::{FilterData, FrameInfo};
-    use chrono::{DateTime, LocalResult, TimeZone, Utc};
     use crabe_framework::data::world::TeamColor;
     use crabe_protocol::protobuf::vision_packet::SslDetectionFrame;
-    use log::error;
 
     mod robot {
         use crate::data::{camera::CamRobot, FrameInfo, TrackedRobot, TrackedRobotMap};
@@ -114,22 +112,6 @@ mod detection {
         }
     }
 
-    fn create_date_time(t_capture: f64) -> DateTime<Utc> {
-        match Utc.timestamp_opt((t_capture) as i64, 0) {
-            LocalResult::Single(dt) => dt,
-            LocalResult::None => {
-                let now_utc = Utc::now();
-                error!("Invalid timestamp, using current time: {}", now_utc);
-                now_utc
-            }
-            LocalResult::Ambiguous(dt_min, dt_max) => {
-                let dt_midpoint = dt_min + (dt_max - dt_min) / 2;
-                error!("Ambiguous timestamp resolved to midpoint: {}", dt_midpoint);
-                dt_midpoint
-            }
-        }
-    }
-
     pub fn handle_detection(
         detection: &SslDetectionFrame,
         filter_data: &mut FilterData,
@@ -138,7 +120,13 @@ mod detection {
         let frame_info = FrameInfo {
             camera_id: detection.camera_id,
             frame_number: detection.frame_number,
-            t_capture: create_date_time(Utc::now().timestamp() as f64),
+            t_capture: filter_data.clock.synchronize(
+                detection.camera_id,
+                detection.t_capture,
+                detection.t_sent,
+                detection.t_capture_camera,
+            ),
+            fused: false,
         };
 
         let mut robot_detection_info = robot::RobotDetectionInfo {
@@ -160,12 +148,12 @@ mod detection {
 }
 
 mod geometry {
-    use crate::data::camera::{CamFieldArc, CamFieldLine};
+    use crate::data::camera::{CamCalibration, CamFieldArc, CamFieldLine};
     use crate::data::{camera::CamGeometry, FilterData};
     use crabe_math::shape::Arc;
     use crabe_math::shape::Line;
     use crabe_protocol::protobuf::vision_packet::SslGeometryData;
-    use nalgebra::Point2;
+    use nalgebra::{Point2, Point3};
     use std::collections::HashMap;
 
     pub fn handle_geometry(geometry: &SslGeometryData, filter_data: &mut FilterData) {
@@ -191,6 +179,7 @@ mod geometry {
             goal_height: geometry.field.goal_height.map(|v| v as f64 / 1000.0),
             ball_radius: geometry.field.ball_radius.map(|v| v as f64 / 1000.0),
             max_robot_radius: geometry.field.max_robot_radius.map(|v| v as f64 / 1000.0),
+            calibrations: HashMap::new(),
         };
 
         geometry.field.field_lines.iter().for_each(|line| {
@@ -224,15 +213,48 @@ mod geometry {
             );
         });
 
+        geometry.calib.iter().for_each(|calib| {
+            let position = match (
+                calib.derived_camera_world_tx,
+                calib.derived_camera_world_ty,
+                calib.derived_camera_world_tz,
+            ) {
+                (Some(x), Some(y), Some(z)) => Some(Point3::new(
+                    x as f64 / 1000.0,
+                    y as f64 / 1000.0,
+                    z as f64 / 1000.0,
+                )),
+                _ => None,
+            };
+
+            cam_geometry.calibrations.insert(
+                calib.camera_id,
+                CamCalibration {
+                    camera_id: calib.camera_id,
+                    focal_length: calib.focal_length as f64,
+                    position,
+                },
+            );
+        });
+
         filter_data.geometry = cam_geometry;
     }
 }
 
-pub struct VisionFilter;
+pub struct VisionFilter {
+    /// Whether the detections are handled, or only the geometry.
+    detections: bool,
+}
 
 impl VisionFilter {
     pub fn new() -> VisionFilter {
-        VisionFilter
+        VisionFilter { detections: true }
+    }
+
+    /// Creates a `VisionFilter` that ignores the detections, when the robots and the ball
+    /// are given by another source.
+    pub fn geometry_only() -> VisionFilter {
+        VisionFilter { detections: false }
     }
 }
 
@@ -244,7 +266,7 @@ impl PreFilter for VisionFilter {
         filter_data: &mut FilterData,
     ) {
         inbound_data.vision_packet.iter().for_each(|packet| {
-            if let Some(detection) = packet.detection.as_ref() {
+            if let Some(detection) = packet.detection.as_ref().filter(|_| self.detections) {
                 detection::handle_detection(detection, filter_data, team_color);
             }
 
@@ -72,6 +72,13 @@ pub trait GuardComponent: Component {
 
 /// The `OutputComponent` trait defines the methods required for a component that sends output
 /// commands and received feedback from the robot.
+/// The world gives the latest pose of the robots, to convert the commands to what the robots
+/// can execute.
 pub trait OutputComponent: Component {
-    fn step(&mut self, commands: CommandMap, tool_commands: ToolCommands) -> FeedbackMap;
+    fn step(
+        &mut self,
+        world: &World,
+        commands: CommandMap,
+        tool_commands: ToolCommands,
+    ) -> FeedbackMap;
 }
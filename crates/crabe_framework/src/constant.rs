@@ -1,3 +1,33 @@
 /// The maximum ID number that can be assigned to a robot in the system.
 /// This value is determined by the rules of the Robocup SSL soccer league.
 pub const MAX_ID_ROBOTS: usize = 15;
+
+/// Radius in meters of a robot.
+pub const ROBOT_RADIUS: f64 = 0.09;
+/// Distance in meters the robots must keep from the ball when it is not in play, as set by the
+/// rules.
+pub const STOP_BALL_DISTANCE: f64 = 0.5;
+/// Distance in meters the robots must keep from the corridor between the ball and its
+/// placement position when the other team places the ball, as set by the rules.
+pub const PLACEMENT_DISTANCE: f64 = 0.5;
+/// Distance in meters behind the ball the robots other than the kicker and the goalkeeper
+/// must keep during a penalty kick, as set by the rules.
+pub const PENALTY_DISTANCE: f64 = 1.0;
+/// Radius in meters of the wheels of a robot.
+pub const WHEEL_RADIUS: f64 = 0.027;
+/// Distance in meters between the center of a robot and its wheels.
+pub const WHEEL_DISTANCE: f64 = 0.081;
+/// Angles in radians of the wheels of a robot around its center, from its forward direction,
+/// in the order front right, back right, back left and front left.
+pub const WHEEL_ANGLES: [f64; 4] = [
+    -std::f64::consts::FRAC_PI_3,
+    -3.0 * std::f64::consts::FRAC_PI_4,
+    3.0 * std::f64::consts::FRAC_PI_4,
+    std::f64::consts::FRAC_PI_3,
+];
+/// Proportional gain from the position error in meters to the velocity in m.s-1
+/// when a backend has to reach a target pose.
+pub const POSE_LINEAR_GAIN: f64 = 1.5;
+/// Proportional gain from the orientation error in radians to the angular velocity in rad.s-1
+/// when a backend has to reach a target pose.
+pub const POSE_ANGULAR_GAIN: f64 = 1.5;
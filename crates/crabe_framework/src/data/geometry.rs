@@ -2,6 +2,8 @@ use crabe_math::shape::Circle;
 use nalgebra::Point2;
 use serde::Serialize;
 
+mod area;
+pub use self::area::Area;
 mod goal;
 pub use self::goal::Goal;
 mod penalty;
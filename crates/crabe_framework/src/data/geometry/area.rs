@@ -0,0 +1,63 @@
+use crate::data::geometry::Penalty;
+use nalgebra::{Point2, Vector2};
+
+/// An axis-aligned rectangular area.
+#[derive(Clone, Debug)]
+pub struct Area {
+    /// The corner with the lowest coordinates.
+    pub min: Point2<f64>,
+    /// The corner with the highest coordinates.
+    pub max: Point2<f64>,
+}
+
+impl Area {
+    /// Creates the area covered by a penalty area, extended by `margin` on each side.
+    pub fn from_penalty(penalty: &Penalty, margin: f64) -> Self {
+        let corner = penalty.top_left_position;
+        let opposite = corner
+            - Vector2::new(
+                corner.x.signum() * penalty.depth,
+                corner.y.signum() * penalty.width,
+            );
+        let margin = Vector2::new(margin, margin);
+        Self {
+            min: corner.inf(&opposite) - margin,
+            max: corner.sup(&opposite) + margin,
+        }
+    }
+
+    /// Returns whether the point is strictly inside the area.
+    pub fn contains(&self, point: &Point2<f64>) -> bool {
+        self.min.x < point.x && point.x < self.max.x && self.min.y < point.y && point.y < self.max.y
+    }
+
+    /// Returns whether the segment goes through the inside of the area.
+    pub fn intersects(&self, start: &Point2<f64>, end: &Point2<f64>) -> bool {
+        let direction = end - start;
+        let (mut t_min, mut t_max) = (0.0_f64, 1.0_f64);
+        for axis in 0..2 {
+            if direction[axis] == 0.0 {
+                if start[axis] <= self.min[axis] || start[axis] >= self.max[axis] {
+                    return false;
+                }
+                continue;
+            }
+
+            let t1 = (self.min[axis] - start[axis]) / direction[axis];
+            let t2 = (self.max[axis] - start[axis]) / direction[axis];
+            t_min = t_min.max(t1.min(t2));
+            t_max = t_max.min(t1.max(t2));
+        }
+        t_min < t_max
+    }
+
+    /// Returns the corners of the area.
+    pub fn corners(&self) -> [Point2<f64>; 4] {
+        [
+            self.min,
+            Point2::new(self.max.x, self.min.y),
+            self.max,
+            Point2::new(self.min.x, self.max.y),
+        ]
+    }
+}
@@ -14,4 +14,13 @@ pub struct Goal {
     pub top_left_position: Point2<f64>,
 }
 
-impl Goal {}
+impl Goal {
+    /// Returns the center of the goal line, in meters.
+    pub fn center(&self) -> Point2<f64> {
+        let corner = self.top_left_position;
+        Point2::new(
+            corner.x - corner.x.signum() * self.depth,
+            corner.y - corner.y.signum() * self.width / 2.0,
+        )
+    }
+}
@@ -1,17 +1,27 @@
+use crate::constant::{
+    POSE_ANGULAR_GAIN, POSE_LINEAR_GAIN, WHEEL_ANGLES, WHEEL_DISTANCE, WHEEL_RADIUS,
+};
+use crate::data::world::Pose;
+use nalgebra::{Isometry2, Matrix4x3, Point2, Vector2, Vector3, Vector4};
 use serde::Deserialize;
 use std::collections::HashMap;
+use std::f64::consts::PI;
 
 /// The FeedbackMap type is a hash map that stores feedback data for robots in the game.
 /// Each robot is identified by its ID.
 pub type FeedbackMap = HashMap<u32, Feedback>;
 
 /// The Feedback struct contains information about the feedback data for a robot in the game.
-#[derive(Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct Feedback {
     /// A boolean value indicating whether the robot has possession of the ball.
     pub has_ball: bool,
     /// The current voltage level of the robot (only in real).
     pub voltage: f32,
+    /// The speeds of the four wheel motors of the robot (only in real).
+    pub wheel_speeds: [f32; 4],
+    /// Whether the capacitor of the kicker is charged, if reported by the robot.
+    pub kicker_charged: Option<bool>,
 }
 
 /// The CommandMap type is a hash map that stores commands to be sent to the robots in the game.
@@ -28,15 +38,173 @@ pub enum Kick {
     ChipKick { power: f32 },
 }
 
+/// The MotionCommand enum describes how a robot must move, in the frame that suits the action
+/// best. The output layer converts it to what the robots or the simulator can execute.
+#[derive(Copy, Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MotionCommand {
+    /// Velocities in the frame of the robot.
+    #[serde(rename_all = "camelCase")]
+    LocalVelocity {
+        /// Velocity forward in m.s-1 (towards the dribbler)
+        forward: f32,
+        /// Velocity to the left in m.s-1
+        left: f32,
+        /// Angular velocity rad.s-1 in (counter-clockwise)
+        angular: f32,
+    },
+    /// Velocities in the frame of the field, as seen in the `World`.
+    #[serde(rename_all = "camelCase")]
+    GlobalVelocity {
+        /// Velocity along the x axis in m.s-1
+        x: f32,
+        /// Velocity along the y axis in m.s-1
+        y: f32,
+        /// Angular velocity rad.s-1 in (counter-clockwise)
+        angular: f32,
+    },
+    /// Angular velocities of the wheels in rad.s-1, positive when pushing the robot
+    /// counter-clockwise.
+    #[serde(rename_all = "camelCase")]
+    WheelVelocity {
+        front_right: f32,
+        back_right: f32,
+        back_left: f32,
+        front_left: f32,
+    },
+    /// Pose to reach in the frame of the field, as seen in the `World`.
+    #[serde(rename_all = "camelCase")]
+    TargetPose {
+        /// Position along the x axis in meters
+        x: f32,
+        /// Position along the y axis in meters
+        y: f32,
+        /// Orientation in radians
+        orientation: f32,
+    },
+}
+
+impl Default for MotionCommand {
+    fn default() -> Self {
+        Self::LocalVelocity {
+            forward: 0.0,
+            left: 0.0,
+            angular: 0.0,
+        }
+    }
+}
+
+/// Returns the matrix giving the wheel velocities from the local velocities of a robot.
+fn wheel_matrix() -> Matrix4x3<f64> {
+    Matrix4x3::from_fn(|wheel, axis| {
+        let angle = WHEEL_ANGLES[wheel];
+        match axis {
+            0 => -angle.sin() / WHEEL_RADIUS,
+            1 => angle.cos() / WHEEL_RADIUS,
+            _ => WHEEL_DISTANCE / WHEEL_RADIUS,
+        }
+    })
+}
+
+impl MotionCommand {
+    /// Creates a command with velocities in the frame of the robot.
+    pub fn local_velocity(velocity: Vector3<f64>) -> Self {
+        Self::LocalVelocity {
+            forward: velocity.x as f32,
+            left: velocity.y as f32,
+            angular: velocity.z as f32,
+        }
+    }
+
+    /// Creates a command with velocities in the frame of the field.
+    pub fn global_velocity(velocity: Vector3<f64>) -> Self {
+        Self::GlobalVelocity {
+            x: velocity.x as f32,
+            y: velocity.y as f32,
+            angular: velocity.z as f32,
+        }
+    }
+
+    /// Creates a command with the wheel velocities giving the velocities in the frame of
+    /// the robot.
+    pub fn wheel_velocity(velocity: Vector3<f64>) -> Self {
+        let wheels = wheel_matrix() * velocity;
+        Self::WheelVelocity {
+            front_right: wheels[0] as f32,
+            back_right: wheels[1] as f32,
+            back_left: wheels[2] as f32,
+            front_left: wheels[3] as f32,
+        }
+    }
+
+    /// Creates a command to reach a pose in the frame of the field.
+    pub fn target_pose(position: Point2<f64>, orientation: f64) -> Self {
+        Self::TargetPose {
+            x: position.x as f32,
+            y: position.y as f32,
+            orientation: orientation as f32,
+        }
+    }
+
+    /// Returns the velocities in the frame of the robot (forward, left and angular) matching
+    /// the command, or `None` if the pose of the robot is needed but unknown.
+    ///
+    /// A target pose is reached with a proportional controller.
+    pub fn to_local(&self, pose: Option<&Pose>) -> Option<Vector3<f64>> {
+        let to_robot = |pose: &Pose, vector: Vector2<f64>| {
+            Isometry2::new(pose.position.coords, pose.orientation).inverse() * vector
+        };
+        match *self {
+            MotionCommand::LocalVelocity {
+                forward,
+                left,
+                angular,
+            } => Some(Vector3::new(forward as f64, left as f64, angular as f64)),
+            MotionCommand::GlobalVelocity { x, y, angular } => pose.map(|pose| {
+                let velocity = to_robot(pose, Vector2::new(x as f64, y as f64));
+                Vector3::new(velocity.x, velocity.y, angular as f64)
+            }),
+            MotionCommand::WheelVelocity {
+                front_right,
+                back_right,
+                back_left,
+                front_left,
+            } => {
+                let wheels = Vector4::new(front_right, back_right, back_left, front_left);
+                wheel_matrix()
+                    .pseudo_inverse(f64::EPSILON)
+                    .ok()
+                    .map(|inverse| inverse * wheels.cast::<f64>())
+            }
+            MotionCommand::TargetPose { x, y, orientation } => pose.map(|pose| {
+                let error = Point2::new(x as f64, y as f64) - pose.position;
+                let error_orientation =
+                    (orientation as f64 - pose.orientation + PI).rem_euclid(2.0 * PI) - PI;
+                let velocity = to_robot(pose, error * POSE_LINEAR_GAIN);
+                Vector3::new(
+                    velocity.x,
+                    velocity.y,
+                    error_orientation * POSE_ANGULAR_GAIN,
+                )
+            }),
+        }
+    }
+
+    /// Returns the velocities in the frame of the field (x, y and angular) matching the
+    /// command for a robot at the given pose.
+    pub fn to_global(&self, pose: &Pose) -> Option<Vector3<f64>> {
+        self.to_local(Some(pose)).map(|local| {
+            let velocity = Isometry2::rotation(pose.orientation) * local.xy();
+            Vector3::new(velocity.x, velocity.y, local.z)
+        })
+    }
+}
+
 #[derive(Copy, Debug, Clone, Default, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Command {
-    /// Velocity forward in m.s-1 (towards the dribbler)
-    pub forward_velocity: f32,
-    /// Velocity to the left in m.s-1
-    pub left_velocity: f32,
-    /// Angular velocity rad.s-1 in (counter-clockwise)
-    pub angular_velocity: f32,
+    /// How the robot must move
+    pub motion: MotionCommand,
     /// Order to charge the capacitor of the robot
     pub charge: bool,
     /// Order to kick the ball, if None doesn't KICK
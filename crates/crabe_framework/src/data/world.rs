@@ -6,7 +6,10 @@ pub use self::robot::{
 use serde_with::serde_as;
 
 mod ball;
-pub use self::ball::Ball;
+pub use self::ball::{Ball, BallFlight, BallKick, BallStatus};
+
+mod possession;
+pub use self::possession::{BallControl, Possession};
 
 mod team;
 pub use self::team::{Team, TeamColor};
@@ -14,6 +17,9 @@ pub use self::team::{Team, TeamColor};
 mod game_data;
 pub use self::game_data::GameData;
 
+mod game_state;
+pub use self::game_state::{GameState, Restart, Stage};
+
 use crate::config::CommonConfig;
 use crate::data::geometry::Geometry;
 
@@ -35,8 +41,10 @@ pub struct World {
     /// A map of all the enemy robots in the game, identified by their unique ID.
     #[serde_as(as = "Vec<(_, _)>")]
     pub enemies_bot: RobotMap<EnemyInfo>,
-    /// The current position and state of the ball, if it is visible.
+    /// The current position and state of the ball, or `None` if the ball is lost.
     pub ball: Option<Ball>,
+    /// Which team controls the ball.
+    pub possession: Possession,
     /// The team color of our team.
     pub team_color: TeamColor,
 }
@@ -55,6 +63,7 @@ impl World {
             allies_bot: Default::default(),
             enemies_bot: Default::default(),
             ball: None,
+            possession: Default::default(),
             team_color,
         }
     }
@@ -1,7 +1,49 @@
+use crate::data::world::TeamColor;
 use chrono::{DateTime, Utc};
 use nalgebra::{Point2, Point3, Vector3};
 use serde::Serialize;
 
+/// The `BallStatus` enum represents whether the ball is currently seen by the cameras.
+#[derive(Serialize, Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum BallStatus {
+    /// The ball is detected by the cameras.
+    #[default]
+    Visible,
+    /// The ball is not detected anymore, its position is predicted from its last known motion.
+    Occluded,
+}
+
+/// The `BallFlight` struct represents the estimated trajectory of a chipped ball.
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BallFlight {
+    /// The position where the ball will touch the ground in meters, with respect to the center of the field.
+    pub landing_position: Point2<f64>,
+    /// The time at which the ball will touch the ground.
+    pub landing_time: DateTime<Utc>,
+    /// The maximal height of the trajectory in meters.
+    pub max_height: f64,
+}
+
+/// The `BallKick` struct represents the last kick of the ball, as reported by an external tracker.
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BallKick {
+    /// The position from which the ball was kicked in meters, with respect to the center of the field.
+    pub position: Point2<f64>,
+    /// The initial velocity of the ball in meters per second.
+    pub velocity: Vector3<f64>,
+    /// The time at which the ball was kicked.
+    pub start_time: DateTime<Utc>,
+    /// The predicted time at which the ball will stop.
+    pub stop_time: Option<DateTime<Utc>>,
+    /// The predicted position where the ball will stop in meters, with respect to the center of the field.
+    pub stop_position: Option<Point2<f64>>,
+    /// The color and the id of the robot that kicked the ball.
+    pub kicker: Option<(TeamColor, u8)>,
+}
+
 /// The `Ball` struct represents the ball in the SSL game.
 #[derive(Serialize, Default, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -14,6 +56,12 @@ pub struct Ball {
     pub velocity: Vector3<f64>,
     /// The acceleration of the ball in 3D space in meters per second squared.
     pub acceleration: Vector3<f64>,
+    /// Whether the ball is seen by the cameras or its position is predicted.
+    pub status: BallStatus,
+    /// The trajectory of the ball if it is in the air after a chip kick.
+    pub flight: Option<BallFlight>,
+    /// The last kick of the ball while it is still moving, if known.
+    pub kick: Option<BallKick>,
 }
 
 impl Ball {
@@ -21,4 +69,9 @@ impl Ball {
     pub fn position_2d(&self) -> Point2<f64> {
         Point2::new(self.position.x, self.position.y)
     }
+
+    /// Returns whether the ball is in the air after a chip kick.
+    pub fn is_airborne(&self) -> bool {
+        self.flight.is_some()
+    }
 }
@@ -1,4 +1,5 @@
-use crate::data::world::{Team, TeamColor};
+use crate::data::world::{GameState, Stage, Team, TeamColor};
+use nalgebra::Point2;
 use serde::Serialize;
 
 /// The `GameData` struct represents the state of the SSL game, including the teams and which team is on the positive half of the field.
@@ -11,6 +12,12 @@ pub struct GameData {
     pub enemy: Team,
     /// The color of the team that is on the positive half of the field.
     pub positive_half: TeamColor,
+    /// The current phase of the match, decoded from the referee commands.
+    pub state: GameState,
+    /// The current stage of the match.
+    pub stage: Stage,
+    /// The position in meters where the ball has to be placed, if any.
+    pub designated_position: Option<Point2<f64>>,
 }
 
 impl GameData {
@@ -20,6 +27,14 @@ impl GameData {
             ally: Team::with_color(team_color),
             enemy: Team::with_color(team_color.opposite()),
             positive_half: team_color.opposite(),
+            state: GameState::default(),
+            stage: Stage::default(),
+            designated_position: None,
         }
     }
+
+    /// Returns whether our team defends the goal on the positive half of the field.
+    pub fn ally_on_positive_half(&self) -> bool {
+        self.positive_half == self.ally.color
+    }
 }
@@ -0,0 +1,61 @@
+use crate::data::world::TeamColor;
+use serde::Serialize;
+
+/// The `Restart` enum represents the set piece that was prepared before a
+/// `NORMAL_START` command was issued by the referee.
+#[derive(Serialize, Copy, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase", tag = "kind", content = "team")]
+pub enum Restart {
+    /// A kickoff taken by the given team.
+    Kickoff(TeamColor),
+    /// A penalty kick taken by the given team.
+    Penalty(TeamColor),
+}
+
+/// The `GameState` enum represents the current phase of the match, as decoded
+/// from the commands sent by the game controller.
+#[derive(Serialize, Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase", tag = "kind", content = "content")]
+pub enum GameState {
+    /// Robots must not move.
+    Halt,
+    /// Robots must slow down and keep their distance from the ball.
+    Stop,
+    /// The given team is preparing a kickoff.
+    PrepareKickoff(TeamColor),
+    /// The given team is preparing a penalty kick.
+    PreparePenalty(TeamColor),
+    /// The given team is allowed to take a free kick.
+    FreeKick(TeamColor),
+    /// The prepared restart may be taken, the game runs as soon as the ball moves.
+    NormalStart(Restart),
+    /// Both teams may play the ball, the game runs as soon as the ball moves.
+    ForceStart,
+    /// The given team has to place the ball on the designated position.
+    BallPlacement(TeamColor),
+    /// The game is running normally. Until a referee packet is received, the game
+    /// is considered as running so that the AI can be used without a game controller.
+    #[default]
+    Running,
+}
+
+/// The `Stage` enum represents the stage of the match (halves, breaks, penalty shootout, ...).
+#[derive(Serialize, Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum Stage {
+    #[default]
+    NormalFirstHalfPre,
+    NormalFirstHalf,
+    NormalHalfTime,
+    NormalSecondHalfPre,
+    NormalSecondHalf,
+    ExtraTimeBreak,
+    ExtraFirstHalfPre,
+    ExtraFirstHalf,
+    ExtraHalfTime,
+    ExtraSecondHalfPre,
+    ExtraSecondHalf,
+    PenaltyShootoutBreak,
+    PenaltyShootout,
+    PostGame,
+}
@@ -0,0 +1,29 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// The `BallControl` enum represents which team controls the ball.
+#[derive(Serialize, Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase", tag = "kind", content = "id")]
+pub enum BallControl {
+    /// No robot holds the ball.
+    #[default]
+    Free,
+    /// The ally robot with the given id holds the ball.
+    Ally(u8),
+    /// The enemy robot with the given id holds the ball.
+    Enemy(u8),
+    /// Robots of both teams hold the ball.
+    Contested,
+}
+
+/// The `Possession` struct summarizes who controls the ball and for how long.
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Possession {
+    /// Which team controls the ball.
+    pub control: BallControl,
+    /// The timestamp from which the ball is controlled this way.
+    pub since: DateTime<Utc>,
+    /// The duration in seconds for which the ball is controlled this way.
+    pub duration: f64,
+}
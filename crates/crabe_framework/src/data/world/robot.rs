@@ -6,7 +6,14 @@ use std::collections::HashMap;
 /// The `AllyInfo` struct represents the information related to allies in the game.
 #[derive(Serialize, Clone, Default, Debug)]
 #[serde(rename_all = "camelCase")]
-pub struct AllyInfo;
+pub struct AllyInfo {
+    /// The battery voltage reported by the robot, if known.
+    pub battery_voltage: Option<f32>,
+    /// Whether the capacitor of the kicker is charged, if reported by the robot.
+    pub kicker_charged: Option<bool>,
+    /// The timestamp of the last feedback received from the robot.
+    pub last_feedback: Option<DateTime<Utc>>,
+}
 
 /// The `EnemyInfo` struct represents the information related to enemies in the game.
 #[derive(Serialize, Clone, Default, Debug)]
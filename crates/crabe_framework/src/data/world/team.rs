@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 /// The `TeamColor` enum represents the color of a team in the SSL game, either blue or yellow.
-#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum TeamColor {
     Blue,
@@ -18,19 +18,35 @@ impl TeamColor {
     }
 }
 
-/// The `Team` struct represents a team in the SSL game, including its color and optional name.
+/// The `Team` struct represents a team in the SSL game, including its color and the
+/// information sent by the game controller.
 #[derive(Serialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct Team {
     /// The color of the team.
-    color: TeamColor,
+    pub color: TeamColor,
     /// The name of the team, if provided.
-    name: Option<String>,
+    pub name: Option<String>,
+    /// The number of goals scored by the team.
+    pub score: u32,
+    /// The number of yellow cards received by the team.
+    pub yellow_cards: u32,
+    /// The id of the goalkeeper of the team.
+    pub goalkeeper: u8,
+    /// The number of timeouts the team has left.
+    pub timeouts: u32,
 }
 
 impl Team {
     /// Creates a new team with the given color.
     pub fn with_color(color: TeamColor) -> Self {
-        Self { color, name: None }
+        Self {
+            color,
+            name: None,
+            score: 0,
+            yellow_cards: 0,
+            goalkeeper: 0,
+            timeouts: 0,
+        }
     }
 }
@@ -1,2 +1,11 @@
 pub const MAX_LINEAR: f32 = 2.;
 pub const MAX_ANGULAR: f32 = std::f32::consts::PI;
+/// Maximum linear speed in m.s-1 allowed by the rules while the game is stopped.
+pub const STOP_MAX_LINEAR: f32 = 1.5;
+/// Speed in m.s-1 at which a robot too close to the ball is pushed away from it.
+pub const STOP_ESCAPE_SPEED: f64 = 0.5;
+/// Duration in seconds over which the commanded velocity of a robot is projected to check
+/// that it stays in the allowed areas of the field.
+pub const GEOMETRY_HORIZON: f64 = 0.3;
+/// Distance in meters kept between a robot and the areas it must not enter.
+pub const GEOMETRY_MARGIN: f64 = 0.02;
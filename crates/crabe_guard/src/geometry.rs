@@ -0,0 +1,105 @@
+use crate::constant::{GEOMETRY_HORIZON, GEOMETRY_MARGIN};
+use crate::pipeline::Guard;
+use crabe_framework::constant::ROBOT_RADIUS;
+use crabe_framework::data::geometry::Area;
+use crabe_framework::data::output::{CommandMap, MotionCommand};
+use crabe_framework::data::tool::ToolCommands;
+use crabe_framework::data::world::{GameState, World};
+use nalgebra::{Point2, Vector2, Vector3};
+
+/// The `GeometryGuard` keeps the robots inside the field, up to its boundary, and keeps the
+/// field players out of both defense areas.
+///
+/// The commanded velocity of each robot is projected `GEOMETRY_HORIZON` seconds ahead. When
+/// the segment it covers crosses a forbidden area, its components leading into the area are
+/// reduced so that the robot stops on the edge while still sliding along it. A robot already
+/// inside a defense area is free to leave it. The goalkeeper may enter the ally defense area,
+/// and any robot may enter the defense areas to place the ball for the ally team.
+#[derive(Default)]
+pub struct GeometryGuard;
+
+/// Returns the velocity keeping the robot at `position` out of the area over the horizon.
+fn avoid_area(position: Point2<f64>, velocity: Vector2<f64>, area: &Area) -> Vector2<f64> {
+    // The whole segment covered over the horizon is checked, so that a fast robot cannot
+    // jump over a corner of the area.
+    if area.contains(&position)
+        || !area.intersects(&position, &(position + velocity * GEOMETRY_HORIZON))
+    {
+        return velocity;
+    }
+
+    // Stop on the sides the robot is facing, which are the only ones it can cross.
+    let mut velocity = velocity;
+    for axis in 0..2 {
+        if position[axis] <= area.min[axis] {
+            velocity[axis] =
+                velocity[axis].min((area.min[axis] - position[axis]) / GEOMETRY_HORIZON);
+        } else if position[axis] >= area.max[axis] {
+            velocity[axis] =
+                velocity[axis].max((area.max[axis] - position[axis]) / GEOMETRY_HORIZON);
+        }
+    }
+    velocity
+}
+
+/// Returns the velocity keeping the robot at `position` inside the area over the horizon,
+/// bringing it back if it is already out. The velocity is not made faster than the incoming
+/// one, so that the limits set by the previous guards still hold.
+fn stay_in_area(position: Point2<f64>, velocity: Vector2<f64>, area: &Area) -> Vector2<f64> {
+    let speed = velocity.norm();
+    let mut velocity = velocity;
+    for axis in 0..2 {
+        velocity[axis] = velocity[axis].clamp(
+            (area.min[axis] - position[axis]) / GEOMETRY_HORIZON,
+            (area.max[axis] - position[axis]) / GEOMETRY_HORIZON,
+        );
+    }
+    velocity.cap_magnitude(speed)
+}
+
+impl Guard for GeometryGuard {
+    fn guard(
+        &mut self,
+        world: &World,
+        commands: &mut CommandMap,
+        _tool_commands: &mut ToolCommands,
+    ) {
+        let geometry = &world.geometry;
+        let margin = ROBOT_RADIUS + GEOMETRY_MARGIN;
+        let half_field = Vector2::new(
+            geometry.field.length / 2.0 + geometry.boundary_width - margin,
+            geometry.field.width / 2.0 + geometry.boundary_width - margin,
+        );
+        let field = Area {
+            min: Point2::from(-half_field),
+            max: Point2::from(half_field),
+        };
+        let placing = world.data.state == GameState::BallPlacement(world.team_color);
+
+        for (id, command) in commands.iter_mut() {
+            let Some(pose) = world.allies_bot.get(id).map(|robot| &robot.pose) else {
+                continue;
+            };
+            let Some(global) = command.motion.to_global(pose) else {
+                continue;
+            };
+
+            let mut velocity = stay_in_area(pose.position, global.xy(), &field);
+            if !placing {
+                let goalkeeper = *id == world.data.ally.goalkeeper;
+                let penalties = [
+                    (!goalkeeper).then_some(&geometry.ally_penalty),
+                    Some(&geometry.enemy_penalty),
+                ];
+                for penalty in penalties.into_iter().flatten() {
+                    let area = Area::from_penalty(penalty, margin);
+                    velocity = avoid_area(pose.position, velocity, &area);
+                }
+            }
+            if velocity != global.xy() {
+                command.motion =
+                    MotionCommand::global_velocity(Vector3::new(velocity.x, velocity.y, global.z));
+            }
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::pipeline::Guard;
+use crabe_framework::data::output::{Command, CommandMap};
+use crabe_framework::data::tool::ToolCommands;
+use crabe_framework::data::world::{GameState, World};
+
+/// The `HaltGuard` stops every robot, including its dribbler and kicker, while the game is
+/// halted. The robots on the field without command are stopped too.
+#[derive(Default)]
+pub struct HaltGuard;
+
+impl Guard for HaltGuard {
+    fn guard(
+        &mut self,
+        world: &World,
+        commands: &mut CommandMap,
+        _tool_commands: &mut ToolCommands,
+    ) {
+        if world.data.state == GameState::Halt {
+            // Every robot is ordered to stop, even the ones without command which would keep
+            // executing their last one.
+            for id in world.allies_bot.keys() {
+                commands.insert(*id, Command::default());
+            }
+            commands
+                .values_mut()
+                .for_each(|command| *command = Command::default());
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use crate::pipeline::Guard;
+use crabe_framework::data::output::CommandMap;
+use crabe_framework::data::tool::ToolCommands;
+use crabe_framework::data::world::{GameState, Restart, World};
+
+/// The `KickGuard` removes the kicks ordered while the ally team is not allowed to play the
+/// ball.
+#[derive(Default)]
+pub struct KickGuard;
+
+/// Returns whether the ally team may kick the ball in the current state of the game.
+fn may_kick(world: &World) -> bool {
+    let ally = world.team_color;
+    match world.data.state {
+        GameState::Running | GameState::ForceStart => true,
+        GameState::NormalStart(Restart::Kickoff(team) | Restart::Penalty(team))
+        | GameState::FreeKick(team)
+        | GameState::BallPlacement(team) => team == ally,
+        GameState::Halt
+        | GameState::Stop
+        | GameState::PrepareKickoff(_)
+        | GameState::PreparePenalty(_) => false,
+    }
+}
+
+impl Guard for KickGuard {
+    fn guard(
+        &mut self,
+        world: &World,
+        commands: &mut CommandMap,
+        _tool_commands: &mut ToolCommands,
+    ) {
+        if !may_kick(world) {
+            commands
+                .values_mut()
+                .for_each(|command| command.kick = None);
+        }
+    }
+}
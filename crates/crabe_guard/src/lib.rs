@@ -1,3 +1,7 @@
 pub mod constant;
+pub mod geometry;
+pub mod halt;
+pub mod kick;
 pub mod pipeline;
 pub mod speed;
+pub mod stop;
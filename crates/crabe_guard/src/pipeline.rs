@@ -1,4 +1,8 @@
+use crate::geometry::GeometryGuard;
+use crate::halt::HaltGuard;
+use crate::kick::KickGuard;
 use crate::speed::SpeedGuard;
+use crate::stop::StopGuard;
 use clap::Args;
 use crabe_framework::component::{Component, GuardComponent};
 use crabe_framework::config::CommonConfig;
@@ -25,7 +29,16 @@ pub struct GuardPipeline {
 impl GuardPipeline {
     pub fn with_config(_guard_cfg: GuardConfig, _common_cfg: &CommonConfig) -> Self {
         Self {
-            guards: vec![Box::<SpeedGuard>::default()],
+            // The rules of the game are enforced after the speed limits, and the geometry after
+            // them so that the robots slowed down by the stop guard still avoid the forbidden
+            // areas. Halting comes last, so that no other guard can break it.
+            guards: vec![
+                Box::<SpeedGuard>::default(),
+                Box::<StopGuard>::default(),
+                Box::<KickGuard>::default(),
+                Box::<GeometryGuard>::default(),
+                Box::<HaltGuard>::default(),
+            ],
         }
     }
 }
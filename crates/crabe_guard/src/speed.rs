@@ -1,9 +1,33 @@
 use crate::constant::{MAX_ANGULAR, MAX_LINEAR};
 use crate::pipeline::Guard;
-use crabe_framework::data::output::CommandMap;
+use crabe_framework::constant::{WHEEL_DISTANCE, WHEEL_RADIUS};
+use crabe_framework::data::output::{CommandMap, MotionCommand};
 use crabe_framework::data::tool::ToolCommands;
 use crabe_framework::data::world::World;
 use log::warn;
+use nalgebra::Vector3;
+
+/// Replaces a NaN value by 0 and clamps the value between `-max` and `max`.
+fn limit(value: &mut f32, max: f32, name: &str) {
+    // nalgebra docs mention you shouldn't compare with f32::NaN and should use the .is_nan() method instead
+    if value.is_nan() {
+        warn!("An attempt was made to send NaN instead of a valid value in {}. It has been adjusted to 0.", name);
+        *value = 0.;
+    } else {
+        *value = value.clamp(-max, max);
+    }
+}
+
+/// Replaces the NaN values by 0 and scales the vector `(x, y)` down to a norm of `max`.
+fn limit_norm(x: &mut f32, y: &mut f32, max: f32, names: (&str, &str)) {
+    limit(x, f32::MAX, names.0);
+    limit(y, f32::MAX, names.1);
+    let norm = x.hypot(*y);
+    if norm > max {
+        *x *= max / norm;
+        *y *= max / norm;
+    }
+}
 
 pub struct SpeedGuard {
     max_linear: f32,
@@ -31,38 +55,60 @@ impl Default for SpeedGuard {
 impl Guard for SpeedGuard {
     fn guard(
         &mut self,
-        _world: &World,
+        world: &World,
         commands: &mut CommandMap,
         _tool_commands: &mut ToolCommands,
     ) {
-        commands.iter_mut().for_each(|(_id, command)| {
-            // Replacing any NaN values that might be computed to 0.
-            // nalgebra docs mention you shouldn't compare with f32::NaN and should use the .is_nan() method instead
-            if command.forward_velocity.is_nan() {
-                warn!("An attempt was made to send NaN instead of a valid value in forward_velocity. It has been adjusted to 0.");
-                command.forward_velocity = 0.;
-            } else {
-                command.forward_velocity = command
-                    .forward_velocity
-                    .clamp(-self.max_linear, self.max_linear);
-            }
-
-            if command.left_velocity.is_nan() {
-                warn!("An attempt was made to send NaN instead of a valid value in left_velocity. It has been adjusted to 0.");
-                command.left_velocity = 0.;
-            } else {
-                command.left_velocity = command
-                    .left_velocity
-                    .clamp(-self.max_linear, self.max_linear);
+        let max_wheel =
+            (self.max_linear + WHEEL_DISTANCE as f32 * self.max_angular) / WHEEL_RADIUS as f32;
+        commands.iter_mut().for_each(|(id, command)| {
+            match &mut command.motion {
+                MotionCommand::LocalVelocity {
+                    forward,
+                    left,
+                    angular,
+                } => {
+                    limit_norm(forward, left, self.max_linear, ("forward", "left"));
+                    limit(angular, self.max_angular, "angular");
+                }
+                MotionCommand::GlobalVelocity { x, y, angular } => {
+                    limit_norm(x, y, self.max_linear, ("x", "y"));
+                    limit(angular, self.max_angular, "angular");
+                }
+                MotionCommand::WheelVelocity {
+                    front_right,
+                    back_right,
+                    back_left,
+                    front_left,
+                } => {
+                    limit(front_right, max_wheel, "front_right");
+                    limit(back_right, max_wheel, "back_right");
+                    limit(back_left, max_wheel, "back_left");
+                    limit(front_left, max_wheel, "front_left");
+                }
+                MotionCommand::TargetPose { x, y, orientation } => {
+                    limit(x, f32::MAX, "x");
+                    limit(y, f32::MAX, "y");
+                    limit(orientation, f32::MAX, "orientation");
+                }
             }
 
-            if command.angular_velocity.is_nan() {
-                warn!("An attempt was made to send NaN instead of a valid value in angular_velocity. It has been adjusted to 0.");
-                command.angular_velocity = command
-                    .angular_velocity
-                    .clamp(-self.max_angular, self.max_angular);
+            // A target pose is reached with velocities proportional to the error, which are
+            // limited like the other commands when the output converts them.
+            if let MotionCommand::TargetPose { .. } = command.motion {
+                let pose = world.allies_bot.get(id).map(|robot| &robot.pose);
+                if let Some(velocity) = command.motion.to_local(pose) {
+                    let max_linear = self.max_linear as f64;
+                    let max_angular = self.max_angular as f64;
+                    let linear = velocity.xy() * (max_linear / velocity.xy().norm()).min(1.0);
+                    let angular = velocity.z.clamp(-max_angular, max_angular);
+                    if linear != velocity.xy() || angular != velocity.z {
+                        command.motion = MotionCommand::local_velocity(Vector3::new(
+                            linear.x, linear.y, angular,
+                        ));
+                    }
+                }
             }
-
         });
     }
 }
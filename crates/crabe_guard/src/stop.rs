@@ -0,0 +1,71 @@
+use crate::constant::{STOP_ESCAPE_SPEED, STOP_MAX_LINEAR};
+use crate::pipeline::Guard;
+use crabe_framework::constant::{ROBOT_RADIUS, STOP_BALL_DISTANCE};
+use crabe_framework::data::output::{CommandMap, MotionCommand};
+use crabe_framework::data::tool::ToolCommands;
+use crabe_framework::data::world::{GameState, World};
+use nalgebra::{Rotation2, Vector2, Vector3};
+
+/// The `StopGuard` enforces the rules of a stopped game: the robots move slower than
+/// `STOP_MAX_LINEAR` and leave the circle of `STOP_BALL_DISTANCE` around the ball.
+///
+/// The motion of a robot is replaced by velocities in the frame of the field, or in its own
+/// frame when its pose is unknown, and the robot is stopped when neither can be computed.
+#[derive(Default)]
+pub struct StopGuard;
+
+impl Guard for StopGuard {
+    fn guard(
+        &mut self,
+        world: &World,
+        commands: &mut CommandMap,
+        _tool_commands: &mut ToolCommands,
+    ) {
+        if world.data.state != GameState::Stop {
+            return;
+        }
+        // The robots without command would keep executing their last one, possibly too fast.
+        for id in world.allies_bot.keys() {
+            commands.entry(*id).or_default();
+        }
+        for (id, command) in commands.iter_mut() {
+            let Some(pose) = world.allies_bot.get(id).map(|robot| &robot.pose) else {
+                command.motion = match command.motion.to_local(None) {
+                    Some(local) => {
+                        let linear = cap(local.xy());
+                        MotionCommand::local_velocity(Vector3::new(linear.x, linear.y, local.z))
+                    }
+                    None => MotionCommand::default(),
+                };
+                continue;
+            };
+            let Some(global) = command.motion.to_global(pose) else {
+                command.motion = MotionCommand::default();
+                continue;
+            };
+
+            let mut linear = global.xy();
+            if let Some(ball) = &world.ball {
+                let away = pose.position - ball.position_2d();
+                if away.norm() < STOP_BALL_DISTANCE + ROBOT_RADIUS {
+                    let away = away
+                        .try_normalize(f64::EPSILON)
+                        .unwrap_or_else(|| Rotation2::new(pose.orientation) * -Vector2::x());
+                    // Keep the motion along the circle, but never slower than the escape
+                    // speed away from the ball.
+                    let outwards = linear.dot(&away);
+                    linear += away * (STOP_ESCAPE_SPEED - outwards).max(0.0);
+                }
+            }
+            let linear = cap(linear);
+            command.motion =
+                MotionCommand::global_velocity(Vector3::new(linear.x, linear.y, global.z));
+        }
+    }
+}
+
+/// Scales the velocity down to the maximum speed allowed while the game is stopped.
+fn cap(velocity: Vector2<f64>) -> Vector2<f64> {
+    let max = STOP_MAX_LINEAR as f64;
+    velocity * (max / velocity.norm().max(max))
+}
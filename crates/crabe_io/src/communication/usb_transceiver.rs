@@ -1,11 +1,14 @@
 use crate::constant::BUFFER_SIZE;
 use log::{debug, error};
 use serialport::SerialPort;
+use std::io;
 use std::time::Duration;
 
 pub struct UsbTransceiver {
     port: Box<dyn SerialPort>,
     buffer: [u8; BUFFER_SIZE],
+    /// The number of bytes received in the buffer that are not yet decoded.
+    received: usize,
 }
 
 impl UsbTransceiver {
@@ -16,7 +19,21 @@ impl UsbTransceiver {
 
         let buffer = [0u8; BUFFER_SIZE];
 
-        Ok(Self { port, buffer })
+        Ok(Self {
+            port,
+            buffer,
+            received: 0,
+        })
+    }
+
+    /// Creates another transceiver on the same serial port, to receive and send from
+    /// different threads.
+    pub fn try_clone(&self) -> Result<Self, serialport::Error> {
+        Ok(Self {
+            port: self.port.try_clone()?,
+            buffer: [0u8; BUFFER_SIZE],
+            received: 0,
+        })
     }
 
     pub fn send<T: prost::Message + Default>(&mut self, packet: T) {
@@ -36,4 +53,47 @@ impl UsbTransceiver {
             }
         }
     }
+
+    /// Reads the bytes available on the serial port and decodes every whole packet of type
+    /// `T` received, using `prost`.
+    ///
+    /// As for `send`, each packet is preceded by a byte containing its encoded length.
+    /// The bytes of an incomplete packet are kept until the rest is received. When a packet
+    /// cannot be decoded, the buffer is dropped to resynchronise on the next packets.
+    ///
+    /// # Returns
+    ///
+    /// The packets received, possibly none, or the error of the serial port if it is not a
+    /// timeout.
+    pub fn receive<T: prost::Message + Default>(&mut self) -> io::Result<Vec<T>> {
+        match self.port.read(&mut self.buffer[self.received..]) {
+            Ok(size) => self.received += size,
+            Err(e) if e.kind() == io::ErrorKind::TimedOut => {}
+            Err(e) => return Err(e),
+        }
+
+        let mut packets = vec![];
+        let mut start = 0;
+        while let Some(&length) = self.buffer[start..self.received].first() {
+            let end = start + 1 + length as usize;
+            if end > self.received {
+                break;
+            }
+            match T::decode(&self.buffer[start + 1..end]) {
+                Ok(packet) => {
+                    debug!("received: {:?}", packet);
+                    packets.push(packet);
+                    start = end;
+                }
+                Err(e) => {
+                    error!("Decoding of the received packet failed: {}", e);
+                    self.received = 0;
+                    return Ok(packets);
+                }
+            }
+        }
+        self.buffer.copy_within(start..self.received, 0);
+        self.received -= start;
+        Ok(packets)
+    }
 }
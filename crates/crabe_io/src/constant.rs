@@ -1,7 +1,14 @@
+use std::time::Duration;
+
 /// Size of the buffer for packet (game_controller, vision, etc...).
 /// This buffer size was chosen to accommodate the largest possible packet size
 /// for the protocols that use it, including overhead and padding.
 pub const BUFFER_SIZE: usize = 4096;
+/// Delay before reading again the serial port after an error, doubled after each
+/// consecutive error.
+pub const USB_ERROR_BACKOFF: Duration = Duration::from_millis(10);
+/// Maximum delay before reading again the serial port after an error.
+pub const USB_ERROR_MAX_BACKOFF: Duration = Duration::from_secs(1);
 pub const VISION_PORT_REAL: u16 = 10006;
 pub const VISION_PORT_SIM: u16 = 10020;
 pub const SIM_PORT_BLUE: u16 = 10301;
@@ -1,24 +1,70 @@
+use crate::constant::{USB_ERROR_BACKOFF, USB_ERROR_MAX_BACKOFF};
 use crate::league::real::RealConfig;
-use log::error;
+use log::{error, info};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::Receiver;
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::thread::JoinHandle;
 
 use crabe_framework::constant::MAX_ID_ROBOTS;
-use crabe_framework::data::output::{Command, CommandMap, FeedbackMap, Kick};
+use crabe_framework::data::output::{
+    Command, CommandMap, Feedback, FeedbackMap, Kick, MotionCommand,
+};
+use log::warn;
 
-use crabe_protocol::protobuf::robot_packet::{BaseCommand, Kicker, PcToBase};
+use crabe_protocol::protobuf::robot_packet::{BaseCommand, BaseToPc, Kicker, PcToBase};
 
 use crate::communication::UsbTransceiver;
 use crate::pipeline::output::CommandSenderTask;
 
 pub struct Real {
     usb: UsbTransceiver,
+    rx_feedback: Receiver<BaseToPc>,
+    handle: Option<JoinHandle<()>>,
+    running: Arc<AtomicBool>,
 }
 
 impl Real {
     pub fn with_config(usb_config: RealConfig) -> Self {
         let usb = UsbTransceiver::new(&usb_config.usb_port, usb_config.usb_baud)
             .expect("Failed to create usb transceiver");
+        let mut usb_reader = usb
+            .try_clone()
+            .expect("Failed to create usb feedback receiver");
 
-        Self { usb }
+        let (tx_feedback, rx_feedback) = mpsc::channel::<BaseToPc>();
+        let running = Arc::new(AtomicBool::new(true));
+        let running_clone = Arc::clone(&running);
+
+        let handle = thread::spawn(move || {
+            let mut backoff = USB_ERROR_BACKOFF;
+            while running_clone.load(Ordering::Relaxed) {
+                match usb_reader.receive::<BaseToPc>() {
+                    Ok(packets) => {
+                        backoff = USB_ERROR_BACKOFF;
+                        for packet in packets {
+                            if let Err(e) = tx_feedback.send(packet) {
+                                error!("USB feedback channel closed, stop reading: {:?}", e);
+                                return;
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        error!("USB receive error, retrying in {:?}: {}", backoff, e);
+                        thread::sleep(backoff);
+                        backoff = (backoff * 2).min(USB_ERROR_MAX_BACKOFF);
+                    }
+                }
+            }
+        });
+
+        Self {
+            usb,
+            rx_feedback,
+            handle: Some(handle),
+            running,
+        }
     }
 
     fn prepare_packet(&mut self, commands: impl Iterator<Item = (u8, Command)>) -> PcToBase {
@@ -30,11 +76,23 @@ impl Real {
                 Some(Kick::ChipKick { power }) => (Kicker::Chip, power),
             };
 
+            let (forward, left, angular) = match command.motion {
+                MotionCommand::LocalVelocity {
+                    forward,
+                    left,
+                    angular,
+                } => (forward, left, angular),
+                _ => {
+                    warn!("unsupported motion command for robot {}", id);
+                    (0.0, 0.0, 0.0)
+                }
+            };
+
             packet.commands.push(BaseCommand {
                 robot_id: id as u32,
-                normal_velocity: command.forward_velocity,
-                tangential_velocity: command.left_velocity,
-                angular_velocity: command.angular_velocity,
+                normal_velocity: forward,
+                tangential_velocity: left,
+                angular_velocity: angular,
                 kick: kicker_cmd.into(),
                 kick_power,
                 charge: command.charge,
@@ -43,6 +101,31 @@ impl Real {
         }
         packet
     }
+
+    /// Returns the last feedback received from each robot since the previous call.
+    fn fetch(&mut self) -> FeedbackMap {
+        let mut feedback_map: FeedbackMap = Default::default();
+        for packet in self.rx_feedback.try_iter() {
+            for feedback in packet.feedbacks {
+                feedback_map.insert(
+                    feedback.robot_id,
+                    Feedback {
+                        has_ball: feedback.ir,
+                        voltage: feedback.voltage,
+                        wheel_speeds: [
+                            feedback.motor_1_speed,
+                            feedback.motor_2_speed,
+                            feedback.motor_3_speed,
+                            feedback.motor_4_speed,
+                        ],
+                        kicker_charged: feedback.kicker_charged,
+                    },
+                );
+            }
+        }
+
+        feedback_map
+    }
 }
 
 impl CommandSenderTask for Real {
@@ -56,7 +139,7 @@ impl CommandSenderTask for Real {
         let packet = self.prepare_packet(commands.into_iter());
         self.usb.send(packet);
 
-        Default::default()
+        self.fetch()
     }
 
     fn close(&mut self) {
@@ -66,5 +149,13 @@ impl CommandSenderTask for Real {
         }
 
         self.step(commands);
+
+        self.running.store(false, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            match handle.join() {
+                Ok(_) => info!("USB feedback Thread finished successfully"),
+                Err(e) => println!("USB feedback thread finished with an error: {:?}", e),
+            }
+        }
     }
 }
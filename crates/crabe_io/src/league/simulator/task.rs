@@ -2,11 +2,13 @@ use crate::communication::UDPTransceiver;
 
 use crate::league::simulator::config::SimulatorConfig;
 
-use crabe_framework::data::output::{Command, CommandMap, Feedback, FeedbackMap, Kick};
+use crabe_framework::data::output::{
+    Command, CommandMap, Feedback, FeedbackMap, Kick, MotionCommand,
+};
 
 use crabe_protocol::protobuf::simulation_packet::{
-    robot_move_command, MoveLocalVelocity, RobotCommand, RobotControl, RobotControlResponse,
-    RobotMoveCommand,
+    robot_move_command, MoveLocalVelocity, MoveWheelVelocity, RobotCommand, RobotControl,
+    RobotControlResponse, RobotMoveCommand,
 };
 use log::debug;
 
@@ -49,16 +51,34 @@ impl Simulator {
                 Some(Kick::ChipKick { power }) => (*power, 45.0),
             };
 
+            let move_command = match command.motion {
+                MotionCommand::WheelVelocity {
+                    front_right,
+                    back_right,
+                    back_left,
+                    front_left,
+                } => robot_move_command::Command::WheelVelocity(MoveWheelVelocity {
+                    front_right,
+                    back_right,
+                    back_left,
+                    front_left,
+                }),
+                MotionCommand::LocalVelocity {
+                    forward,
+                    left,
+                    angular,
+                } => robot_move_command::Command::LocalVelocity(MoveLocalVelocity {
+                    forward,
+                    left,
+                    angular,
+                }),
+                _ => robot_move_command::Command::LocalVelocity(MoveLocalVelocity::default()),
+            };
+
             let robot_command = RobotCommand {
                 id: id as u32,
                 move_command: Some(RobotMoveCommand {
-                    command: Some(robot_move_command::Command::LocalVelocity(
-                        MoveLocalVelocity {
-                            forward: command.forward_velocity,
-                            left: command.left_velocity,
-                            angular: command.angular_velocity,
-                        },
-                    )),
+                    command: Some(move_command),
                 }),
                 kick_speed: Some(kick_speed),
                 kick_angle: Some(kick_angle),
@@ -83,7 +103,9 @@ impl Simulator {
                     robot_feedback.id,
                     Feedback {
                         has_ball: robot_feedback.dribbler_ball_contact(),
-                        voltage: Default::default(),
+                        // The simulation protocol reports neither the voltage nor the charge of
+                        // the kicker.
+                        ..Default::default()
                     },
                 );
             }
@@ -100,6 +122,15 @@ impl CommandSenderTask for Simulator {
         self.fetch()
     }
 
+    /// The global velocities are converted to local ones because the `World` may be rotated
+    /// from the frame of the simulator when the field is normalized.
+    fn supports(&self, motion: &MotionCommand) -> bool {
+        matches!(
+            motion,
+            MotionCommand::LocalVelocity { .. } | MotionCommand::WheelVelocity { .. }
+        )
+    }
+
     fn close(&mut self) {
         let mut commands: CommandMap = Default::default();
         for id in 0..MAX_ID_ROBOTS {
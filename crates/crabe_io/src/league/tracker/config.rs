@@ -0,0 +1,12 @@
+use clap::Args;
+
+/// Represents the configuration settings for an external tracker broadcasting
+/// tracked frames, such as the one of the autoRefs.
+#[derive(Args)]
+pub struct TrackerConfig {
+    #[arg(long, default_value = "224.5.23.2")]
+    pub tracker_ip: String,
+
+    #[arg(long, default_value_t = 10010)]
+    pub tracker_port: u16,
+}
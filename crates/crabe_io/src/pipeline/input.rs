@@ -1,4 +1,5 @@
 use crate::league::game_controller::{GameController, GameControllerConfig};
+use crate::league::tracker::{Tracker, TrackerConfig};
 use crate::league::vision::{Vision, VisionConfig};
 use clap::Args;
 use crabe_framework::component::{Component, InputComponent};
@@ -11,6 +12,10 @@ pub struct InputConfig {
     #[arg(long)]
     gc: bool,
 
+    /// Receive the tracked frames of an external tracker.
+    #[arg(long)]
+    pub tracker: bool,
+
     #[command(flatten)]
     #[command(next_help_heading = "Vision")]
     pub vision_cfg: VisionConfig,
@@ -18,6 +23,10 @@ pub struct InputConfig {
     #[command(flatten)]
     #[command(next_help_heading = "Game Controller")]
     pub gc_cfg: GameControllerConfig,
+
+    #[command(flatten)]
+    #[command(next_help_heading = "Tracker")]
+    pub tracker_cfg: TrackerConfig,
 }
 
 pub trait ReceiverTask {
@@ -40,6 +49,10 @@ impl InputPipeline {
             tasks.push(Box::new(GameController::with_config(input_cfg.gc_cfg)));
         }
 
+        if input_cfg.tracker {
+            tasks.push(Box::new(Tracker::with_config(input_cfg.tracker_cfg)));
+        }
+
         Self { receivers: tasks }
     }
 }
@@ -51,8 +64,11 @@ impl Component for InputPipeline {
 }
 
 impl InputComponent for InputPipeline {
-    fn step(&mut self, _feedback: &mut FeedbackMap) -> InboundData {
-        let mut data = InboundData::default();
+    fn step(&mut self, feedback: &mut FeedbackMap) -> InboundData {
+        let mut data = InboundData {
+            feedback: std::mem::take(feedback),
+            ..Default::default()
+        };
         self.receivers.iter_mut().for_each(|x| x.fetch(&mut data));
         data
     }
@@ -1,13 +1,15 @@
 use crabe_framework::component::{Component, OutputComponent};
 use crabe_framework::config::CommonConfig;
 
-use crabe_framework::data::output::{CommandMap, FeedbackMap};
+use crabe_framework::data::output::{CommandMap, FeedbackMap, MotionCommand};
 use crabe_framework::data::tool::ToolCommands;
+use crabe_framework::data::world::World;
 
 use crate::league::real::{Real, RealConfig};
 use crate::league::simulator::config::SimulatorConfig;
 use crate::league::simulator::task::Simulator;
 use clap::Args;
+use log::warn;
 
 #[derive(Args)]
 pub struct OutputConfig {
@@ -23,6 +25,12 @@ pub struct OutputConfig {
 pub trait CommandSenderTask {
     fn step(&mut self, commands: CommandMap) -> FeedbackMap;
     fn close(&mut self);
+
+    /// Returns whether the backend can execute the motion command as is. The other commands
+    /// are converted to local velocities before being sent.
+    fn supports(&self, motion: &MotionCommand) -> bool {
+        matches!(motion, MotionCommand::LocalVelocity { .. })
+    }
 }
 
 pub struct OutputPipeline {
@@ -48,7 +56,30 @@ impl Component for OutputPipeline {
 }
 
 impl OutputComponent for OutputPipeline {
-    fn step(&mut self, commands: CommandMap, _tool_commands: ToolCommands) -> FeedbackMap {
+    fn step(
+        &mut self,
+        world: &World,
+        mut commands: CommandMap,
+        _tool_commands: ToolCommands,
+    ) -> FeedbackMap {
+        for (id, command) in commands.iter_mut() {
+            if self.command_task.supports(&command.motion) {
+                continue;
+            }
+
+            let pose = world.allies_bot.get(id).map(|robot| &robot.pose);
+            command.motion = match command.motion.to_local(pose) {
+                Some(velocity) => MotionCommand::local_velocity(velocity),
+                None => {
+                    warn!(
+                        "cannot convert the motion command of robot {}, stopping it",
+                        id
+                    );
+                    MotionCommand::default()
+                }
+            };
+        }
+
         self.command_task.step(commands)
     }
 }
@@ -0,0 +1,26 @@
+pub use crabe_framework::constant::ROBOT_RADIUS;
+/// Additional distance in meters kept between two robots when planning a path.
+pub const OBSTACLE_MARGIN: f64 = 0.05;
+/// Number of vertices of the polygon approximating an obstacle in the visibility graph.
+pub const OBSTACLE_VERTICES: usize = 8;
+/// Radius in meters of the ball.
+pub const BALL_RADIUS: f64 = 0.0215;
+/// Distance in meters kept between a robot and the ball when it must avoid it.
+pub const BALL_AVOIDANCE_MARGIN: f64 = 0.2;
+/// Maximum linear speed in m.s-1 of a robot, matching the default limit of the speed guard.
+pub const MAX_LINEAR_SPEED: f64 = 2.0;
+/// Duration in seconds during which the velocity chosen by the collision avoidance must not
+/// lead to a collision.
+pub const ORCA_TIME_HORIZON: f64 = 1.0;
+/// Duration in seconds between two commands sent to a robot.
+pub const CONTROL_PERIOD: f64 = 1.0 / 60.0;
+/// Maximum linear acceleration in m.s-2 of a robot.
+pub const MAX_LINEAR_ACCELERATION: f64 = 3.0;
+/// Maximum linear deceleration in m.s-2 of a robot.
+pub const MAX_LINEAR_DECELERATION: f64 = 4.0;
+/// Maximum angular speed in rad.s-1 of a robot, matching the default limit of the speed guard.
+pub const MAX_ANGULAR_SPEED: f64 = std::f64::consts::PI;
+/// Maximum angular acceleration in rad.s-2 of a robot.
+pub const MAX_ANGULAR_ACCELERATION: f64 = 10.0;
+/// Maximum angular deceleration in rad.s-2 of a robot.
+pub const MAX_ANGULAR_DECELERATION: f64 = 12.0;
@@ -0,0 +1,186 @@
+use crate::constant::{BALL_AVOIDANCE_MARGIN, BALL_RADIUS, OBSTACLE_MARGIN, ROBOT_RADIUS};
+use crabe_framework::constant::PLACEMENT_DISTANCE;
+use crabe_framework::data::geometry::Area;
+use crabe_framework::data::world::World;
+use crabe_math::shape::Circle;
+use nalgebra::{Point2, Vector2};
+
+/// Returns the distance between a point and a segment.
+pub fn distance_to_segment(point: &Point2<f64>, start: &Point2<f64>, end: &Point2<f64>) -> f64 {
+    let segment = end - start;
+    let length_squared = segment.norm_squared();
+    if length_squared == 0.0 {
+        return (point - start).norm();
+    }
+
+    let t = ((point - start).dot(&segment) / length_squared).clamp(0.0, 1.0);
+    (point - (start + segment * t)).norm()
+}
+
+/// The `Avoidance` struct tells what a robot must keep away from while moving,
+/// in addition to the other robots which are always avoided.
+#[derive(Clone, Copy, Debug)]
+pub struct Avoidance {
+    /// Keep a safety distance around the ball.
+    pub avoid_ball: bool,
+    /// Stay out of both penalty areas.
+    pub avoid_penalty_areas: bool,
+    /// Allow the robot to push the ball. When disabled the robot can come close to the ball
+    /// without touching it.
+    pub touch_ball: bool,
+    /// Stay out of the corridor between the ball and the position where it is placed by the
+    /// referee, during a ball placement.
+    pub avoid_placement: bool,
+}
+
+impl Default for Avoidance {
+    fn default() -> Self {
+        Self {
+            avoid_ball: false,
+            avoid_penalty_areas: false,
+            touch_ball: true,
+            avoid_placement: false,
+        }
+    }
+}
+
+impl Avoidance {
+    /// Returns the radius of the area around the ball the robot must not enter,
+    /// or `None` if the ball is not an obstacle.
+    pub fn ball_radius(&self) -> Option<f64> {
+        if self.avoid_ball {
+            Some(BALL_RADIUS + BALL_AVOIDANCE_MARGIN)
+        } else if !self.touch_ball {
+            Some(BALL_RADIUS)
+        } else {
+            None
+        }
+    }
+}
+
+/// The `Environment` struct describes the area in which the center of a robot can move:
+/// the obstacles to go around and the bounds of the field.
+#[derive(Clone, Debug)]
+pub struct Environment {
+    /// The circular areas the center of the robot must not enter.
+    pub obstacles: Vec<Circle>,
+    /// The rectangular areas the center of the robot must not enter.
+    pub areas: Vec<Area>,
+    /// Half of the length and of the width of the rectangle, centered on the origin,
+    /// in which the center of the robot must stay.
+    pub bounds: Vector2<f64>,
+}
+
+impl Environment {
+    /// Creates an environment without obstacles.
+    pub fn new(bounds: Vector2<f64>) -> Self {
+        Self {
+            obstacles: vec![],
+            areas: vec![],
+            bounds,
+        }
+    }
+
+    /// Creates the environment of the ally robot `id`, in which every other robot on the field
+    /// is an obstacle and the robot must stay inside the field and its boundary.
+    /// The ball and the penalty areas are obstacles depending on the `avoidance`.
+    pub fn from_world(id: u8, world: &World, avoidance: &Avoidance) -> Self {
+        let geometry = &world.geometry;
+        let bounds = Vector2::new(
+            geometry.field.length / 2.0 + geometry.boundary_width - ROBOT_RADIUS,
+            geometry.field.width / 2.0 + geometry.boundary_width - ROBOT_RADIUS,
+        );
+
+        let allies = world
+            .allies_bot
+            .values()
+            .filter(|robot| robot.id != id)
+            .map(|robot| robot.pose.position);
+        let enemies = world.enemies_bot.values().map(|robot| robot.pose.position);
+        let mut environment = Self::new(bounds);
+        for center in allies.chain(enemies) {
+            environment.add_obstacle(center, 2.0 * ROBOT_RADIUS + OBSTACLE_MARGIN);
+        }
+
+        if let (Some(ball), Some(radius)) = (&world.ball, avoidance.ball_radius()) {
+            environment.add_obstacle(ball.position_2d(), ROBOT_RADIUS + radius);
+        }
+        if let (true, Some(ball), Some(designated)) = (
+            avoidance.avoid_placement,
+            &world.ball,
+            world.data.designated_position,
+        ) {
+            environment.add_corridor(
+                ball.position_2d(),
+                designated,
+                PLACEMENT_DISTANCE + ROBOT_RADIUS + OBSTACLE_MARGIN,
+            );
+        }
+        if avoidance.avoid_penalty_areas {
+            for penalty in [&geometry.ally_penalty, &geometry.enemy_penalty] {
+                environment
+                    .areas
+                    .push(Area::from_penalty(penalty, ROBOT_RADIUS + OBSTACLE_MARGIN));
+            }
+        }
+        environment
+    }
+
+    /// Adds a circular obstacle to the environment.
+    pub fn add_obstacle(&mut self, center: Point2<f64>, radius: f64) {
+        self.obstacles.push(Circle { center, radius });
+    }
+
+    /// Adds circular obstacles covering the corridor of the given radius around the segment
+    /// between `start` and `end`.
+    pub fn add_corridor(&mut self, start: Point2<f64>, end: Point2<f64>, radius: f64) {
+        // Circles spaced by half of their radius leave about 3% of the radius uncovered
+        // on the edges of the corridor.
+        let segment = end - start;
+        let count = (2.0 * segment.norm() / radius).ceil().max(1.0) as usize;
+        for i in 0..=count {
+            self.add_obstacle(start + segment * (i as f64 / count as f64), radius);
+        }
+    }
+
+    /// Removes the obstacles and the areas containing the point.
+    pub fn release(&mut self, point: &Point2<f64>) {
+        self.obstacles
+            .retain(|obstacle| (point - obstacle.center).norm() >= obstacle.radius);
+        self.areas.retain(|area| !area.contains(point));
+    }
+
+    /// Returns whether the point is inside the bounds.
+    pub fn contains(&self, point: &Point2<f64>) -> bool {
+        point.x.abs() <= self.bounds.x && point.y.abs() <= self.bounds.y
+    }
+
+    /// Returns the closest point to `point` inside the bounds.
+    pub fn clamp(&self, point: &Point2<f64>) -> Point2<f64> {
+        Point2::new(
+            point.x.clamp(-self.bounds.x, self.bounds.x),
+            point.y.clamp(-self.bounds.y, self.bounds.y),
+        )
+    }
+
+    /// Returns whether the point is inside the bounds and outside of every obstacle and area.
+    pub fn is_free(&self, point: &Point2<f64>) -> bool {
+        self.contains(point)
+            && self
+                .obstacles
+                .iter()
+                .all(|obstacle| (point - obstacle.center).norm() >= obstacle.radius)
+            && !self.areas.iter().any(|area| area.contains(point))
+    }
+
+    /// Returns whether the segment does not cross any obstacle or area.
+    ///
+    /// The bounds are not checked: since they are convex, a segment between two points
+    /// inside them stays inside them.
+    pub fn is_segment_free(&self, start: &Point2<f64>, end: &Point2<f64>) -> bool {
+        self.obstacles
+            .iter()
+            .all(|obstacle| distance_to_segment(&obstacle.center, start, end) >= obstacle.radius)
+            && !self.areas.iter().any(|area| area.intersects(start, end))
+    }
+}
@@ -1,14 +1,25 @@
-pub fn add(left: usize, right: usize) -> usize {
-    left + right
-}
+//! # CRAbE_Navigation
+//!
+//! This crate computes the paths followed by the robots on the field, avoiding
+//! the other robots and staying inside the playing area.
+//!
+//! It includes the following modules:
+//!
+//! - `environment`: the obstacles and the bounds a path must respect, built from the `World`.
+//! - `motion_profile`: the velocity profiles respecting the acceleration limits of a robot.
+//! - `path_planner`: the `PathPlanner` trait and its implementations.
+//! - `velocity_obstacle`: the local collision avoidance between moving robots.
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
-    }
-}
+/// This module provides constants related to the `crabe_navigation` package.
+pub mod constant;
+/// The `environment` module describes the area in which a path is planned.
+pub mod environment;
+/// The `motion_profile` module computes the velocity of a robot moving towards a target
+/// within its speed and acceleration limits.
+pub mod motion_profile;
+/// The `path_planner` module contains the `PathPlanner` trait and the planners
+/// implementing it, which compute the waypoints followed by a robot.
+pub mod path_planner;
+/// The `velocity_obstacle` module adjusts the velocity of a robot every step to avoid
+/// collisions with the moving robots around it.
+pub mod velocity_obstacle;
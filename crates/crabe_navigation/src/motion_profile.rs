@@ -0,0 +1,123 @@
+use crate::constant::{
+    CONTROL_PERIOD, MAX_ANGULAR_ACCELERATION, MAX_ANGULAR_DECELERATION, MAX_ANGULAR_SPEED,
+    MAX_LINEAR_ACCELERATION, MAX_LINEAR_DECELERATION, MAX_LINEAR_SPEED,
+};
+use nalgebra::Vector2;
+
+/// The `Trapezoidal` struct generates a time-optimal velocity profile along one axis:
+/// accelerate as much as possible, cruise at the maximum velocity, then brake as late as
+/// possible to stop on the target.
+///
+/// The profile is re-planned every period from the current velocity, so only the velocity
+/// to reach at the end of the next period is computed.
+#[derive(Clone, Copy, Debug)]
+pub struct Trapezoidal {
+    pub max_velocity: f64,
+    pub max_acceleration: f64,
+    pub max_deceleration: f64,
+}
+
+impl Trapezoidal {
+    /// Creates a new `Trapezoidal` profile with the given limits.
+    pub fn new(max_velocity: f64, max_acceleration: f64, max_deceleration: f64) -> Self {
+        Self {
+            max_velocity,
+            max_acceleration,
+            max_deceleration,
+        }
+    }
+
+    /// Returns the velocity to command for the next `period` seconds to reach the point at
+    /// the signed `distance` along the axis, moving at `velocity`.
+    pub fn velocity(&self, distance: f64, velocity: f64, period: f64) -> f64 {
+        // The fastest velocity from which the robot can still stop on the target when the
+        // velocity decreases by steps of one period.
+        let step = self.max_deceleration * period / 2.0;
+        let braking = ((step.powi(2) + 2.0 * self.max_deceleration * distance.abs()).sqrt() - step)
+            .min(distance.abs() / period);
+        let desired = distance.signum() * braking.min(self.max_velocity);
+
+        let accelerating = desired * velocity >= 0.0 && desired.abs() > velocity.abs();
+        let max_change = if accelerating {
+            self.max_acceleration
+        } else {
+            self.max_deceleration
+        } * period;
+        velocity + (desired - velocity).clamp(-max_change, max_change)
+    }
+}
+
+/// The `MotionProfile` struct limits the velocity, the acceleration and the deceleration of a
+/// robot moving towards a target, on its linear and angular axes.
+#[derive(Clone, Copy, Debug)]
+pub struct MotionProfile {
+    pub linear: Trapezoidal,
+    pub angular: Trapezoidal,
+    /// The duration in seconds between two commands.
+    pub period: f64,
+}
+
+impl Default for MotionProfile {
+    fn default() -> Self {
+        Self {
+            linear: Trapezoidal::new(
+                MAX_LINEAR_SPEED,
+                MAX_LINEAR_ACCELERATION,
+                MAX_LINEAR_DECELERATION,
+            ),
+            angular: Trapezoidal::new(
+                MAX_ANGULAR_SPEED,
+                MAX_ANGULAR_ACCELERATION,
+                MAX_ANGULAR_DECELERATION,
+            ),
+            period: CONTROL_PERIOD,
+        }
+    }
+}
+
+impl MotionProfile {
+    /// Returns the linear velocity to command to travel `distance` meters in the unit
+    /// `direction`, moving at `velocity`.
+    ///
+    /// The velocity along the direction follows the trapezoidal profile, while the
+    /// velocity across it is cancelled at the maximum deceleration.
+    pub fn linear_velocity(
+        &self,
+        direction: Vector2<f64>,
+        distance: f64,
+        velocity: Vector2<f64>,
+    ) -> Vector2<f64> {
+        let along = velocity.dot(&direction);
+        let across = velocity - direction * along;
+        let across_change = self.linear.max_deceleration * self.period;
+        let across = across * (1.0 - across_change / across.norm().max(across_change));
+
+        direction * self.linear.velocity(distance, along, self.period) + across
+    }
+
+    /// Returns the angular velocity to command to turn of `angle` radians, rotating at
+    /// `velocity`.
+    pub fn angular_velocity(&self, angle: f64, velocity: f64) -> f64 {
+        self.angular.velocity(angle, velocity, self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stops_on_target() {
+        let profile = Trapezoidal::new(2.0, 3.0, 4.0);
+        let period = 0.01;
+        let (mut position, mut velocity) = (0.0, 0.0);
+        for _ in 0..1000 {
+            velocity = profile.velocity(1.0 - position, velocity, period);
+            assert!(velocity <= 2.0);
+            position += velocity * period;
+            assert!(position < 1.0 + 1e-3);
+        }
+        assert!((position - 1.0).abs() < 1e-3);
+        assert!(velocity.abs() < 1e-3);
+    }
+}
@@ -0,0 +1,18 @@
+mod visibility_graph;
+pub use self::visibility_graph::VisibilityGraph;
+
+use crate::environment::Environment;
+use nalgebra::Point2;
+
+/// The `PathPlanner` trait is implemented by the algorithms computing the path of a robot
+/// in an `Environment`.
+pub trait PathPlanner {
+    /// Returns the waypoints leading from `start` to `goal`, without `start` and ending with
+    /// `goal`, or `None` if the goal cannot be reached.
+    fn plan(
+        &mut self,
+        start: Point2<f64>,
+        goal: Point2<f64>,
+        environment: &Environment,
+    ) -> Option<Vec<Point2<f64>>>;
+}
@@ -0,0 +1,287 @@
+use crate::constant::OBSTACLE_VERTICES;
+use crate::environment::Environment;
+use crate::path_planner::PathPlanner;
+use nalgebra::{center, Point2, Vector2};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::f64::consts::PI;
+
+/// Factor applied to the radius of the polygons around the obstacles, so that their edges
+/// do not touch the obstacles because of rounding errors.
+const VERTEX_CLEARANCE: f64 = 1.01;
+/// Distance in meters between the corners of the areas and the nodes placed around them.
+const CORNER_CLEARANCE: f64 = 0.01;
+/// Ratio between the longest path searched first and the straight line to the goal.
+const PRUNING_RATIO: f64 = 1.5;
+/// Length in meters added to the longest path searched first, so that short moves can
+/// still go around an obstacle.
+const PRUNING_MARGIN: f64 = 1.0;
+
+/// A node of the graph waiting to be explored, ordered by increasing estimated cost.
+struct Candidate {
+    cost: f64,
+    index: usize,
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cost == other.cost
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost.total_cmp(&self.cost)
+    }
+}
+
+/// The `VisibilityGraph` planner approximates each obstacle with a polygon surrounding it and
+/// searches the shortest path going through the vertices of these polygons and the corners
+/// of the areas with A*.
+///
+/// The obstacles and the areas containing the start or the goal are ignored, so that a robot
+/// too close to another one can still move away from it.
+#[derive(Clone, Debug)]
+pub struct VisibilityGraph {
+    /// The number of vertices of the polygon around each obstacle.
+    vertices: usize,
+}
+
+impl Default for VisibilityGraph {
+    fn default() -> Self {
+        Self::new(OBSTACLE_VERTICES)
+    }
+}
+
+impl VisibilityGraph {
+    /// Creates a new `VisibilityGraph` approximating the obstacles with polygons
+    /// of `vertices` vertices.
+    pub fn new(vertices: usize) -> Self {
+        Self {
+            vertices: vertices.max(3),
+        }
+    }
+
+    /// Returns the vertices of the polygons around the obstacles and the corners of the areas
+    /// that can be reached, keeping only the ones through which a path from `start` to `goal`
+    /// is shorter than `max_length`.
+    fn nodes(
+        &self,
+        environment: &Environment,
+        start: &Point2<f64>,
+        goal: &Point2<f64>,
+        max_length: f64,
+    ) -> Vec<Point2<f64>> {
+        let step = 2.0 * PI / self.vertices as f64;
+        let scale = VERTEX_CLEARANCE / (step / 2.0).cos();
+        let mut nodes = vec![];
+        for obstacle in &environment.obstacles {
+            let radius = obstacle.radius * scale;
+            nodes.extend((0..self.vertices).map(|i| {
+                let angle = step * i as f64;
+                obstacle.center + Vector2::new(angle.cos(), angle.sin()) * radius
+            }));
+        }
+        for area in &environment.areas {
+            let center = center(&area.min, &area.max);
+            nodes.extend(area.corners().map(|corner| {
+                let outward = corner - center;
+                corner + Vector2::new(outward.x.signum(), outward.y.signum()) * CORNER_CLEARANCE
+            }));
+        }
+        nodes.retain(|node| {
+            (node - start).norm() + (goal - node).norm() <= max_length && environment.is_free(node)
+        });
+        nodes
+    }
+
+    /// Searches the shortest path from `start` to `goal` through the `nodes` with A*.
+    ///
+    /// The visibility between two nodes is only checked when going through it would improve
+    /// the cost of the next node, which avoids most of the checks against the obstacles.
+    fn search(
+        start: Point2<f64>,
+        goal: Point2<f64>,
+        nodes: &[Point2<f64>],
+        environment: &Environment,
+    ) -> Option<Vec<Point2<f64>>> {
+        let nodes: Vec<Point2<f64>> = [start, goal].iter().chain(nodes).copied().collect();
+        let (start_index, goal_index) = (0, 1);
+        let mut costs = vec![f64::INFINITY; nodes.len()];
+        let mut previous: Vec<Option<usize>> = vec![None; nodes.len()];
+        let mut closed = vec![false; nodes.len()];
+        let mut open = BinaryHeap::new();
+        costs[start_index] = 0.0;
+        open.push(Candidate {
+            cost: (goal - start).norm(),
+            index: start_index,
+        });
+
+        while let Some(Candidate { index, .. }) = open.pop() {
+            if closed[index] {
+                continue;
+            }
+            closed[index] = true;
+
+            if index == goal_index {
+                let mut path = vec![];
+                let mut current = goal_index;
+                while let Some(parent) = previous[current] {
+                    path.push(nodes[current]);
+                    current = parent;
+                }
+                path.reverse();
+                return Some(path);
+            }
+
+            for next in 0..nodes.len() {
+                let cost = costs[index] + (nodes[next] - nodes[index]).norm();
+                if closed[next]
+                    || cost >= costs[next]
+                    || !environment.is_segment_free(&nodes[index], &nodes[next])
+                {
+                    continue;
+                }
+
+                costs[next] = cost;
+                previous[next] = Some(index);
+                open.push(Candidate {
+                    cost: cost + (goal - nodes[next]).norm(),
+                    index: next,
+                });
+            }
+        }
+
+        None
+    }
+}
+
+impl PathPlanner for VisibilityGraph {
+    /// Plans the path with the nodes close to the straight line from `start` to `goal` first,
+    /// and with every node only if no such path exists.
+    ///
+    /// A `start` outside of the bounds is first brought back to the closest point inside
+    /// them, and a `goal` outside of the bounds is replaced by the closest point inside them,
+    /// so that no edge leaves the field.
+    fn plan(
+        &mut self,
+        start: Point2<f64>,
+        goal: Point2<f64>,
+        environment: &Environment,
+    ) -> Option<Vec<Point2<f64>>> {
+        let entry = environment.clamp(&start);
+        let goal = environment.clamp(&goal);
+        let mut environment = environment.clone();
+        environment.release(&entry);
+        environment.release(&goal);
+
+        let mut path = if environment.is_segment_free(&entry, &goal) {
+            vec![goal]
+        } else {
+            let direct = (goal - entry).norm();
+            let detour = PRUNING_RATIO * direct + PRUNING_MARGIN;
+            [detour, f64::INFINITY].into_iter().find_map(|max_length| {
+                let nodes = self.nodes(&environment, &entry, &goal, max_length);
+                Self::search(entry, goal, &nodes, &environment)
+            })?
+        };
+        if entry != start {
+            path.insert(0, entry);
+        }
+        Some(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crabe_framework::data::geometry::Area;
+
+    fn environment() -> Environment {
+        Environment::new(Vector2::new(4.5, 3.0))
+    }
+
+    #[test]
+    fn straight_line_without_obstacle() {
+        let goal = Point2::new(2.0, 1.0);
+        let path = VisibilityGraph::default().plan(Point2::origin(), goal, &environment());
+        assert_eq!(path, Some(vec![goal]));
+    }
+
+    #[test]
+    fn avoids_obstacle() {
+        let mut environment = environment();
+        environment.add_obstacle(Point2::new(1.0, 0.0), 0.3);
+        let start = Point2::origin();
+        let goal = Point2::new(2.0, 0.0);
+
+        let path = VisibilityGraph::default()
+            .plan(start, goal, &environment)
+            .expect("a path around the obstacle");
+        assert_eq!(path.last(), Some(&goal));
+        assert!(path.len() > 1);
+        let mut previous = start;
+        for waypoint in path {
+            assert!(environment.is_segment_free(&previous, &waypoint));
+            previous = waypoint;
+        }
+    }
+
+    #[test]
+    fn avoids_area() {
+        let mut environment = environment();
+        environment.areas.push(Area {
+            min: Point2::new(-0.5, -1.0),
+            max: Point2::new(0.5, 1.0),
+        });
+        let start = Point2::new(-1.0, 0.0);
+        let goal = Point2::new(1.0, 0.0);
+
+        let path = VisibilityGraph::default()
+            .plan(start, goal, &environment)
+            .expect("a path around the area");
+        assert_eq!(path.len(), 3);
+        let mut previous = start;
+        for waypoint in path {
+            assert!(environment.is_segment_free(&previous, &waypoint));
+            previous = waypoint;
+        }
+    }
+
+    #[test]
+    fn stays_in_bounds() {
+        let environment = environment();
+        let start = Point2::new(5.0, 0.0);
+        let goal = Point2::new(0.0, 4.0);
+
+        let path = VisibilityGraph::default()
+            .plan(start, goal, &environment)
+            .expect("a path back into the bounds");
+        assert_eq!(path, vec![Point2::new(4.5, 0.0), Point2::new(0.0, 3.0)]);
+    }
+
+    #[test]
+    fn finds_long_detour() {
+        let mut environment = environment();
+        environment.areas.push(Area {
+            min: Point2::new(-0.1, -2.9),
+            max: Point2::new(0.1, 3.0),
+        });
+        let start = Point2::new(-0.5, 2.5);
+        let goal = Point2::new(0.5, 2.5);
+
+        let path = VisibilityGraph::default()
+            .plan(start, goal, &environment)
+            .expect("a path around the end of the wall");
+        assert_eq!(path.last(), Some(&goal));
+        assert!(path.iter().any(|waypoint| waypoint.y < -2.9));
+    }
+}
@@ -0,0 +1,374 @@
+use crate::constant::{CONTROL_PERIOD, OBSTACLE_MARGIN, ORCA_TIME_HORIZON, ROBOT_RADIUS};
+use crate::environment::Avoidance;
+use crabe_framework::data::world::World;
+use nalgebra::{Point2, Vector2};
+
+/// Tolerance used to detect parallel lines.
+const EPSILON: f64 = 1e-6;
+
+fn det(a: &Vector2<f64>, b: &Vector2<f64>) -> f64 {
+    a.x * b.y - a.y * b.x
+}
+
+/// A moving circular obstacle seen by the collision avoidance.
+#[derive(Clone, Debug)]
+pub struct MovingObstacle {
+    pub position: Point2<f64>,
+    pub velocity: Vector2<f64>,
+    pub radius: f64,
+    /// Whether the obstacle avoids us as well, in which case each one takes half of the
+    /// effort needed to avoid the collision.
+    pub reciprocal: bool,
+}
+
+impl MovingObstacle {
+    /// Returns the obstacles the ally robot `id` must avoid: the other robots on the field,
+    /// and the ball depending on the `avoidance`.
+    pub fn from_world(id: u8, world: &World, avoidance: &Avoidance) -> Vec<Self> {
+        let allies = world
+            .allies_bot
+            .values()
+            .filter(|robot| robot.id != id)
+            .map(|robot| Self {
+                position: robot.pose.position,
+                velocity: robot.velocity.linear,
+                radius: ROBOT_RADIUS + OBSTACLE_MARGIN,
+                reciprocal: true,
+            });
+        let enemies = world.enemies_bot.values().map(|robot| Self {
+            position: robot.pose.position,
+            velocity: robot.velocity.linear,
+            radius: ROBOT_RADIUS + OBSTACLE_MARGIN,
+            reciprocal: false,
+        });
+        let ball = world
+            .ball
+            .as_ref()
+            .zip(avoidance.ball_radius())
+            .map(|(ball, radius)| Self {
+                position: ball.position_2d(),
+                velocity: ball.velocity.xy(),
+                radius,
+                reciprocal: false,
+            });
+        allies.chain(enemies).chain(ball).collect()
+    }
+}
+
+/// A directed line bounding the allowed velocities, which are on its left.
+#[derive(Clone, Debug)]
+struct Line {
+    point: Vector2<f64>,
+    direction: Vector2<f64>,
+}
+
+/// The `Orca` struct implements the Optimal Reciprocal Collision Avoidance, which chooses the
+/// velocity closest to the preferred one among the velocities that do not lead to a collision
+/// during a time horizon.
+///
+/// Each obstacle restricts the velocities to a half-plane, and the best velocity is found with
+/// a two dimensional linear program. When the constraints cannot all be satisfied, the velocity
+/// minimizing the maximum penetration into the half-planes is chosen instead.
+#[derive(Clone, Debug)]
+pub struct Orca {
+    time_horizon: f64,
+    time_step: f64,
+}
+
+impl Default for Orca {
+    fn default() -> Self {
+        Self::new(ORCA_TIME_HORIZON, CONTROL_PERIOD)
+    }
+}
+
+impl Orca {
+    /// Creates a new `Orca` avoiding collisions during `time_horizon` seconds, for velocities
+    /// updated every `time_step` seconds.
+    pub fn new(time_horizon: f64, time_step: f64) -> Self {
+        Self {
+            time_horizon,
+            time_step,
+        }
+    }
+
+    /// Returns the velocity closest to `preferred`, with a norm lower than `max_speed`,
+    /// which avoids the obstacles.
+    ///
+    /// # Arguments
+    ///
+    /// * `position`, `velocity` and `radius`: the current state of the robot.
+    /// * `preferred`: the velocity the robot would have without obstacles.
+    /// * `max_speed`: the maximum speed of the robot.
+    /// * `obstacles`: the obstacles to avoid.
+    pub fn velocity(
+        &self,
+        position: Point2<f64>,
+        velocity: Vector2<f64>,
+        radius: f64,
+        preferred: Vector2<f64>,
+        max_speed: f64,
+        obstacles: &[MovingObstacle],
+    ) -> Vector2<f64> {
+        let lines: Vec<Line> = obstacles
+            .iter()
+            .map(|obstacle| self.constraint(position, velocity, radius, obstacle))
+            .collect();
+
+        let mut result = Vector2::zeros();
+        let failure = linear_program_2(&lines, max_speed, &preferred, false, &mut result);
+        if failure < lines.len() {
+            linear_program_3(&lines, failure, max_speed, &mut result);
+        }
+        result
+    }
+
+    /// Returns the half-plane of the velocities avoiding the obstacle.
+    fn constraint(
+        &self,
+        position: Point2<f64>,
+        velocity: Vector2<f64>,
+        radius: f64,
+        obstacle: &MovingObstacle,
+    ) -> Line {
+        let relative_position = obstacle.position - position;
+        let relative_velocity = velocity - obstacle.velocity;
+        let distance_squared = relative_position.norm_squared();
+        let combined_radius = radius + obstacle.radius;
+        let combined_radius_squared = combined_radius.powi(2);
+
+        let (direction, u) = if distance_squared > combined_radius_squared {
+            let inverse_horizon = 1.0 / self.time_horizon;
+            // Vector from the center of the cut-off circle to the relative velocity.
+            let w = relative_velocity - relative_position * inverse_horizon;
+            let w_length_squared = w.norm_squared();
+            let dot = w.dot(&relative_position);
+
+            if dot < 0.0 && dot.powi(2) > combined_radius_squared * w_length_squared {
+                // Project on the cut-off circle.
+                let w_length = w_length_squared.sqrt();
+                let unit_w = w / w_length;
+                (
+                    Vector2::new(unit_w.y, -unit_w.x),
+                    unit_w * (combined_radius * inverse_horizon - w_length),
+                )
+            } else {
+                // Project on the closest leg of the cone.
+                let leg = (distance_squared - combined_radius_squared).sqrt();
+                let p = relative_position;
+                let direction = if det(&p, &w) > 0.0 {
+                    Vector2::new(
+                        p.x * leg - p.y * combined_radius,
+                        p.x * combined_radius + p.y * leg,
+                    ) / distance_squared
+                } else {
+                    -Vector2::new(
+                        p.x * leg + p.y * combined_radius,
+                        -p.x * combined_radius + p.y * leg,
+                    ) / distance_squared
+                };
+                let projection = relative_velocity.dot(&direction);
+                (direction, direction * projection - relative_velocity)
+            }
+        } else {
+            // The robots already overlap, move apart during the next step.
+            let inverse_step = 1.0 / self.time_step;
+            let w = relative_velocity - relative_position * inverse_step;
+            let w_length = w.norm();
+            let unit_w = w.try_normalize(EPSILON).unwrap_or_else(|| {
+                -relative_position
+                    .try_normalize(EPSILON)
+                    .unwrap_or(Vector2::x())
+            });
+            (
+                Vector2::new(unit_w.y, -unit_w.x),
+                unit_w * (combined_radius * inverse_step - w_length),
+            )
+        };
+
+        let responsibility = if obstacle.reciprocal { 0.5 } else { 1.0 };
+        Line {
+            point: velocity + u * responsibility,
+            direction,
+        }
+    }
+}
+
+/// Finds the optimal velocity on the line `index`, satisfying the previous lines and the
+/// maximum speed. Returns `false` if there is none.
+fn linear_program_1(
+    lines: &[Line],
+    index: usize,
+    radius: f64,
+    optimal: &Vector2<f64>,
+    optimize_direction: bool,
+    result: &mut Vector2<f64>,
+) -> bool {
+    let line = &lines[index];
+    let dot = line.point.dot(&line.direction);
+    let discriminant = dot.powi(2) + radius.powi(2) - line.point.norm_squared();
+    if discriminant < 0.0 {
+        // The maximum speed circle does not intersect the line.
+        return false;
+    }
+
+    let sqrt_discriminant = discriminant.sqrt();
+    let mut t_left = -dot - sqrt_discriminant;
+    let mut t_right = -dot + sqrt_discriminant;
+    for other in &lines[..index] {
+        let denominator = det(&line.direction, &other.direction);
+        let numerator = det(&other.direction, &(line.point - other.point));
+        if denominator.abs() <= EPSILON {
+            // The lines are parallel.
+            if numerator < 0.0 {
+                return false;
+            }
+            continue;
+        }
+
+        let t = numerator / denominator;
+        if denominator >= 0.0 {
+            t_right = t_right.min(t);
+        } else {
+            t_left = t_left.max(t);
+        }
+        if t_left > t_right {
+            return false;
+        }
+    }
+
+    let t = if optimize_direction {
+        if optimal.dot(&line.direction) > 0.0 {
+            t_right
+        } else {
+            t_left
+        }
+    } else {
+        line.direction
+            .dot(&(optimal - line.point))
+            .clamp(t_left, t_right)
+    };
+    *result = line.point + line.direction * t;
+    true
+}
+
+/// Finds the velocity closest to `optimal` satisfying all the lines and the maximum speed,
+/// or the furthest velocity in the direction `optimal` if `optimize_direction` is set.
+/// Returns the index of the first line which cannot be satisfied, or the number of lines.
+fn linear_program_2(
+    lines: &[Line],
+    radius: f64,
+    optimal: &Vector2<f64>,
+    optimize_direction: bool,
+    result: &mut Vector2<f64>,
+) -> usize {
+    *result = if optimize_direction {
+        optimal * radius
+    } else if optimal.norm_squared() > radius.powi(2) {
+        optimal.normalize() * radius
+    } else {
+        *optimal
+    };
+
+    for (index, line) in lines.iter().enumerate() {
+        if det(&line.direction, &(line.point - *result)) > 0.0 {
+            let previous = *result;
+            if !linear_program_1(lines, index, radius, optimal, optimize_direction, result) {
+                *result = previous;
+                return index;
+            }
+        }
+    }
+    lines.len()
+}
+
+/// Finds the velocity minimizing the maximum distance to the lines it does not satisfy,
+/// starting from the line `begin` which could not be satisfied.
+fn linear_program_3(lines: &[Line], begin: usize, radius: f64, result: &mut Vector2<f64>) {
+    let mut distance = 0.0;
+    for (index, line) in lines.iter().enumerate().skip(begin) {
+        if det(&line.direction, &(line.point - *result)) <= distance {
+            continue;
+        }
+
+        let mut projected_lines = vec![];
+        for other in &lines[..index] {
+            let determinant = det(&line.direction, &other.direction);
+            let point = if determinant.abs() <= EPSILON {
+                if line.direction.dot(&other.direction) > 0.0 {
+                    // The lines point in the same direction.
+                    continue;
+                }
+                (line.point + other.point) * 0.5
+            } else {
+                line.point
+                    + line.direction
+                        * (det(&other.direction, &(line.point - other.point)) / determinant)
+            };
+            projected_lines.push(Line {
+                point,
+                direction: (other.direction - line.direction).normalize(),
+            });
+        }
+
+        let previous = *result;
+        let direction = Vector2::new(-line.direction.y, line.direction.x);
+        if linear_program_2(&projected_lines, radius, &direction, true, result)
+            < projected_lines.len()
+        {
+            // Only happens because of rounding errors, keep the previous result.
+            *result = previous;
+        }
+        distance = det(&line.direction, &(line.point - *result));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::environment::distance_to_segment;
+
+    #[test]
+    fn keeps_preferred_velocity_without_collision() {
+        let orca = Orca::default();
+        let obstacle = MovingObstacle {
+            position: Point2::new(0.0, 2.0),
+            velocity: Vector2::zeros(),
+            radius: 0.1,
+            reciprocal: false,
+        };
+        let preferred = Vector2::new(1.0, 0.0);
+        let velocity = orca.velocity(
+            Point2::origin(),
+            preferred,
+            0.1,
+            preferred,
+            2.0,
+            &[obstacle],
+        );
+        assert!((velocity - preferred).norm() < 1e-9);
+    }
+
+    #[test]
+    fn avoids_head_on_collision() {
+        let orca = Orca::default();
+        let obstacle = MovingObstacle {
+            position: Point2::new(1.0, 0.0),
+            velocity: Vector2::zeros(),
+            radius: 0.1,
+            reciprocal: false,
+        };
+        let preferred = Vector2::new(1.5, 0.0);
+        let velocity = orca.velocity(
+            Point2::origin(),
+            preferred,
+            0.1,
+            preferred,
+            2.0,
+            &[obstacle],
+        );
+        // No collision during the time horizon.
+        let end = Point2::from(velocity * ORCA_TIME_HORIZON);
+        assert!(distance_to_segment(&Point2::new(1.0, 0.0), &Point2::origin(), &end) >= 0.2 - 1e-9);
+        assert!(velocity.norm() <= 2.0 + 1e-9);
+    }
+}
@@ -29,6 +29,12 @@ fn main() {
         &["protobuf/vision"],
     );
 
+    compile_packet(
+        "tracked_packet",
+        &["protobuf/vision/messages_robocup_ssl_wrapper_tracked.proto"],
+        &["protobuf/vision"],
+    );
+
     compile_packet(
         "game_controller_packet",
         &["protobuf/game_controller/ssl_gc_referee_message.proto"],
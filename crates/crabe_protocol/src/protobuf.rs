@@ -1,4 +1,5 @@
 pub mod game_controller_packet;
 pub mod robot_packet;
 pub mod simulation_packet;
+pub mod tracked_packet;
 pub mod vision_packet;
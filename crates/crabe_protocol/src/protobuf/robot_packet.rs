@@ -69,6 +69,9 @@ pub struct BaseFeedback {
     pub voltage: f32,
     #[prost(bool, tag = "7")]
     pub ir: bool,
+    /// Unset by the firmwares not reporting the charge
+    #[prost(bool, optional, tag = "8")]
+    pub kicker_charged: ::core::option::Option<bool>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -45,6 +45,9 @@ pub struct SslDetectionFrame {
     pub t_capture: f64,
     #[prost(double, required, tag = "3")]
     pub t_sent: f64,
+    /// Capture timestamp of the camera hardware, if available
+    #[prost(double, optional, tag = "8")]
+    pub t_capture_camera: ::core::option::Option<f64>,
     #[prost(uint32, required, tag = "4")]
     pub camera_id: u32,
     #[prost(message, repeated, tag = "5")]